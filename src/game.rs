@@ -1,25 +1,50 @@
-use std::{collections::HashMap, time::Duration};
+use std::{collections::HashMap, marker::PhantomData, time::Duration};
 
-use bevy::ecs::schedule::ShouldRun;
+use bevy::{
+    ecs::{schedule::ShouldRun, system::SystemParam},
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    transform::TransformSystem,
+};
 use bevy_asset_loader::prelude::*;
 
 use crate::*;
 
 const TOP_BAR_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.5);
-const TOP_BAR_HEIGHT: f32 = 40.0;
+pub const TOP_BAR_HEIGHT: f32 = 40.0;
 
 const BOTTOM_BAR_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.5);
-const BOTTOM_BAR_HEIGHT: f32 = 50.0;
+pub const BOTTOM_BAR_HEIGHT: f32 = 50.0;
 
 const NUM_PLANT_SPACES: usize = 4;
 pub const PLANT_SPACE_SIZE: f32 = 200.0;
 pub const PLANT_SPACE_HEIGHT: f32 = 300.0;
 const PLANT_SPACE_MARGIN: f32 = 10.0;
 
-const NUM_SEED_SPACES: usize = 4;
+pub const NUM_SEED_SPACES: usize = 4;
+pub const SEEDS_PER_POD: usize = 3;
 const SEED_SPACE_SIZE: f32 = 100.0;
 const SEED_SPACE_MARGIN: f32 = 10.0;
 
+const FAILED_SEED_COLOR: Color = Color::rgb(0.4, 0.4, 0.4);
+
+// how long to wait between each thing that happens when watching a replay, in seconds
+const REPLAY_STEP_DURATION: f32 = 0.8;
+
+// how long each part of the transition between seasons takes, in seconds
+const GROWING_DURATION: f32 = 1.5;
+const PESTS_DURATION: f32 = 1.2;
+// how big a sprouting plant starts out, compared to its full size
+const SPROUT_START_SCALE: f32 = 0.1;
+// how far a plant being eaten by pests shakes, in radians, and how quickly
+const PEST_SHAKE_ANGLE: f32 = 0.12;
+const PEST_SHAKE_SPEED: f32 = 30.0;
+
+const FOCUS_HIGHLIGHT_COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.25);
+const SELECTION_HIGHLIGHT_COLOR: Color = Color::rgba(1.0, 0.8, 0.0, 0.35);
+
+// how far one line of mouse wheel scrolling moves a scrolling list, in pixels
+const SCROLL_LINE_HEIGHT: f32 = 20.0;
+
 const SEED_TOOLTIP_WIDTH: f32 = 200.0;
 const SEED_TOOLTIP_OFFSET: f32 = -15.0;
 
@@ -28,18 +53,26 @@ const MAX_PEST_RESISTANCE: usize = 10;
 
 pub const GOAL_INTELLIGENCE: i32 = 10;
 
+pub const BACKGROUND_MUSIC_VOLUME: f64 = 0.33;
+// how quickly the music's layers fade in and out, as a fraction of full volume per second
+const MUSIC_FADE_SPEED: f64 = 0.4;
+// the layers on top of the background track are each about as loud as the whole track, so they're mixed in quieter
+const MUSIC_LAYER_LEVEL: f64 = 0.5;
+const MUSIC_FADE_OUT_DURATION: Duration = Duration::from_secs(2);
+const SOUND_EFFECT_VOLUME: f64 = 0.5;
+
 const HELP_TEXT: &str = include_str!("../assets/help.txt");
 
-const SEEDS_SECTION_WIDTH: f32 = WINDOW_WIDTH * 0.25;
-const SEEDS_SECTION_HEIGHT: f32 = WINDOW_HEIGHT - TOP_BAR_HEIGHT - BOTTOM_BAR_HEIGHT;
+pub const SEEDS_SECTION_WIDTH: f32 = WINDOW_WIDTH * 0.25;
+pub const SEEDS_SECTION_HEIGHT: f32 = WINDOW_HEIGHT - TOP_BAR_HEIGHT - BOTTOM_BAR_HEIGHT;
 
 const PLANTS_SECTION_WIDTH: f32 = WINDOW_WIDTH * 0.75;
 const PLANTS_SECTION_HEIGHT: f32 = WINDOW_HEIGHT - TOP_BAR_HEIGHT - BOTTOM_BAR_HEIGHT;
 
-const SEEDS_SECTION_START_X: f32 = -(WINDOW_WIDTH / 2.0);
+pub const SEEDS_SECTION_START_X: f32 = -(WINDOW_WIDTH / 2.0);
 const PLANTS_SECTION_START_X: f32 = -(WINDOW_WIDTH / 2.0) + SEEDS_SECTION_WIDTH;
 
-const SECTION_MARGIN: f32 = 20.0;
+pub const SECTION_MARGIN: f32 = 20.0;
 
 const BACKGROUND_LAYER: f32 = 10.0;
 pub const MIDDLE_LAYER: f32 = 20.0;
@@ -66,7 +99,8 @@ impl Plugin for GamePlugin {
 
         app.add_system_set(
             SystemSet::on_enter(GameState::Game)
-                .with_system(game_setup)
+                .with_system(replay_setup.before(game_setup))
+                .with_system(game_setup.label(GameSystem::Setup))
                 .with_system(start_background_music),
         )
         .add_system_set(
@@ -74,22 +108,83 @@ impl Plugin for GamePlugin {
                 .with_system(stop_background_music)
                 .with_system(despawn_components_system::<GameComponent>),
         )
-        .add_system(next_season_button_system)
+        .add_system_set(
+            SystemSet::on_pause(GameState::Game)
+                .with_system(despawn_components_system::<PauseMenuComponent>),
+        )
+        .add_system_set(SystemSet::on_resume(GameState::Game).with_system(pause_menu_setup))
+        .add_system(next_season_button_system.label(GameSystem::Input))
+        .add_system(next_season_button_text_system)
+        .add_system(
+            skip_season_transition_system
+                .with_run_criteria(is_set_up)
+                .before(keyboard_shortcuts_system),
+        )
+        .add_system(
+            season_transition_system
+                .label(GameSystem::SeasonTransition)
+                .with_run_criteria(is_set_up)
+                .after(keyboard_shortcuts_system)
+                .after(next_season_button_system),
+        )
+        .add_system_to_stage(
+            CoreStage::PostUpdate,
+            season_transition_animation_system.before(TransformSystem::TransformPropagate),
+        )
         .add_system(restart_button_system)
+        .add_system(pause_button_system)
+        .add_system(pause_key_system.before(keyboard_focus_system))
+        .add_system(resume_button_system)
+        .add_system(pause_settings_button_system)
+        .add_system(quit_to_menu_button_system)
+        .add_system(confirm_button_system)
+        .add_system(cancel_confirmation_button_system)
         .add_system(help_button_system)
         .add_system(close_help_button_system)
+        .add_system(synergies_button_system)
+        .add_system(close_synergies_button_system)
+        .add_system(season_log_button_system)
+        .add_system(close_season_log_button_system)
+        .add_system(close_season_report_button_system)
+        .add_system(scrolling_list_system)
+        .add_system(hint_button_system.with_run_criteria(is_playing))
+        .add_system(hint_button_text_system)
+        .add_system(
+            season_log_display_system
+                .with_run_criteria(is_set_up)
+                .after(season_transition_system),
+        )
+        .add_system(discover_gene_interactions_system.with_run_criteria(is_set_up))
+        .add_system(
+            seed_pod_display_system
+                .with_run_criteria(is_set_up)
+                .after(plant_splice_system),
+        )
+        .add_system(keep_seed_button_system.with_run_criteria(is_playing))
+        .add_system(discard_seed_pod_button_system.with_run_criteria(is_playing))
+        .add_system(
+            replay_playback_system
+                .with_run_criteria(is_set_up)
+                .before(season_transition_system),
+        )
         .add_system(
             plant_display_system
                 .with_run_criteria(is_set_up)
-                .after(seed_plant_system),
+                .after(seed_plant_system)
+                .after(plant_move_system),
         )
         .add_system(
             seed_display_system
                 .with_run_criteria(is_set_up)
                 .after(seed_plant_system),
         )
+        .add_system(
+            diversity_display_system
+                .with_run_criteria(is_set_up)
+                .after(seed_plant_system),
+        )
         .add_system(being_dragged_system)
-        .add_system(draggable_pickup_system)
+        .add_system(draggable_pickup_system.with_run_criteria(is_playing))
         .add_system(
             plant_splice_system
                 .after(being_dragged_system)
@@ -100,30 +195,150 @@ impl Plugin for GamePlugin {
                 .after(being_dragged_system)
                 .before(draggable_drop_system),
         )
+        .add_system(
+            plant_move_system
+                .after(being_dragged_system)
+                .before(draggable_drop_system),
+        )
         .add_system(draggable_drop_system.after(being_dragged_system))
+        .add_system(
+            keyboard_shortcuts_system
+                .label(GameSystem::Input)
+                .with_run_criteria(is_playing),
+        )
+        .add_system(keyboard_focus_system.with_run_criteria(is_playing))
+        .add_system(
+            focus_highlight_system
+                .with_run_criteria(is_set_up)
+                .after(keyboard_focus_system),
+        )
         .add_system(seed_tooltip_system.after(draggable_drop_system))
-        .add_system(check_lose_system.with_run_criteria(is_set_up))
+        .add_system(check_lose_system.with_run_criteria(is_settled))
         .add_system(
             check_win_system
                 .after(check_lose_system)
-                .with_run_criteria(is_set_up),
+                .with_run_criteria(is_settled),
         )
+        .add_system(music_mix_system.with_run_criteria(is_set_up))
+        .add_event::<SeedCreated>()
+        .add_event::<SeedPlanted>()
+        .add_event::<PlantGrew>()
+        .add_event::<PlantDied>()
+        .add_event::<SeasonAdvanced>()
+        .add_event::<GameWon>()
+        .add_event::<GameLost>()
+        .add_system(game_event_log_system)
         .add_audio_channel::<BackgroundChannel>()
+        .add_audio_channel::<MusicBassChannel>()
+        .add_audio_channel::<MusicPulseChannel>()
+        .add_audio_channel::<MusicShimmerChannel>()
         .add_audio_channel::<ForegroundChannel>()
+        .insert_resource(MusicMix::default())
         .insert_resource(SetUp(false))
+        .insert_resource(Paused(false))
+        .insert_resource(SeasonTransition::default())
+        .insert_resource(SeasonLog(Vec::new()))
         .insert_resource(Season(1))
         .insert_resource(Planters(Vec::new()))
         .insert_resource(Seeds(Vec::new()))
-        .insert_resource(SmartPlant(None));
+        .insert_resource(SmartPlant(None))
+        .insert_resource(DiscoveredGeneInteractions(Vec::new()))
+        .insert_resource(SexedFlowers(false))
+        .insert_resource(HintsUsed(0))
+        .insert_resource(SeedPod(Vec::new()))
+        .insert_resource(KeyboardFocus {
+            focused: None,
+            selected: None,
+        });
     }
 }
 
+/// Labels for the game's systems that other plugins need to run theirs around.
+#[derive(SystemLabel, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum GameSystem {
+    /// Sets up a new game, including the starting plants.
+    Setup,
+    /// Responds to the player asking to move on to the next season.
+    Input,
+    /// Moves on to the next season once it's been asked for.
+    SeasonTransition,
+}
+
 #[derive(AssetCollection)]
 pub struct AudioAssets {
     #[asset(path = "sounds/game_background_music.ogg")]
     background_music: Handle<AudioSource>,
+    #[asset(path = "sounds/music_bass.wav")]
+    music_bass: Handle<AudioSource>,
+    #[asset(path = "sounds/music_pulse.wav")]
+    music_pulse: Handle<AudioSource>,
+    #[asset(path = "sounds/music_shimmer.wav")]
+    music_shimmer: Handle<AudioSource>,
     #[asset(path = "sounds/victory.ogg")]
-    pub victory: Handle<AudioSource>,
+    victory: Handle<AudioSource>,
+    #[asset(path = "sounds/lose.wav")]
+    lose: Handle<AudioSource>,
+    #[asset(path = "sounds/pick_up.wav")]
+    pick_up: Handle<AudioSource>,
+    #[asset(path = "sounds/drop.wav")]
+    drop: Handle<AudioSource>,
+    #[asset(path = "sounds/splice.wav")]
+    splice: Handle<AudioSource>,
+    #[asset(path = "sounds/splice_rejected.wav")]
+    splice_rejected: Handle<AudioSource>,
+    #[asset(path = "sounds/plant.wav")]
+    plant: Handle<AudioSource>,
+    #[asset(path = "sounds/next_season.wav")]
+    next_season: Handle<AudioSource>,
+    #[asset(path = "sounds/eaten_by_pests.wav")]
+    eaten_by_pests: Handle<AudioSource>,
+}
+
+/// A short sound that's played when something happens.
+#[derive(Clone, Copy)]
+pub enum SoundEffect {
+    PickUp,
+    Drop,
+    Splice,
+    SpliceRejected,
+    Plant,
+    NextSeason,
+    EatenByPests,
+    Victory,
+    Lose,
+}
+
+/// Plays sound effects through the foreground channel at the volume from the settings.
+#[derive(SystemParam)]
+pub struct SoundEffects<'w, 's> {
+    audio: Res<'w, AudioChannel<ForegroundChannel>>,
+    // the sounds aren't loaded until the game is, so there's nothing to play before then
+    audio_assets: Option<Res<'w, AudioAssets>>,
+    settings: Res<'w, Settings>,
+    #[system_param(ignore)]
+    marker: PhantomData<&'s ()>,
+}
+
+impl<'w, 's> SoundEffects<'w, 's> {
+    pub fn play(&self, sound_effect: SoundEffect) {
+        if let Some(audio_assets) = &self.audio_assets {
+            let sound = match sound_effect {
+                SoundEffect::PickUp => &audio_assets.pick_up,
+                SoundEffect::Drop => &audio_assets.drop,
+                SoundEffect::Splice => &audio_assets.splice,
+                SoundEffect::SpliceRejected => &audio_assets.splice_rejected,
+                SoundEffect::Plant => &audio_assets.plant,
+                SoundEffect::NextSeason => &audio_assets.next_season,
+                SoundEffect::EatenByPests => &audio_assets.eaten_by_pests,
+                SoundEffect::Victory => &audio_assets.victory,
+                SoundEffect::Lose => &audio_assets.lose,
+            };
+
+            self.audio
+                .play(sound.clone())
+                .with_volume(SOUND_EFFECT_VOLUME * self.settings.sfx_volume);
+        }
+    }
 }
 
 #[derive(AssetCollection)]
@@ -134,6 +349,18 @@ pub struct ImageAssets {
     pub fruit_square: Handle<Image>,
     #[asset(path = "images/fruit_triangle.png")]
     pub fruit_triangle: Handle<Image>,
+    #[asset(path = "images/fruit_circle_stripes.png")]
+    pub fruit_circle_stripes: Handle<Image>,
+    #[asset(path = "images/fruit_square_stripes.png")]
+    pub fruit_square_stripes: Handle<Image>,
+    #[asset(path = "images/fruit_triangle_stripes.png")]
+    pub fruit_triangle_stripes: Handle<Image>,
+    #[asset(path = "images/fruit_circle_dots.png")]
+    pub fruit_circle_dots: Handle<Image>,
+    #[asset(path = "images/fruit_square_dots.png")]
+    pub fruit_square_dots: Handle<Image>,
+    #[asset(path = "images/fruit_triangle_dots.png")]
+    pub fruit_triangle_dots: Handle<Image>,
     #[asset(path = "images/stem_angular.png")]
     pub stem_angular: Handle<Image>,
     #[asset(path = "images/stem_curvy.png")]
@@ -163,20 +390,62 @@ pub struct ImageAssets {
 }
 
 #[derive(Component)]
-struct GameComponent;
+pub struct GameComponent;
 
 #[derive(Component)]
 struct LoadingComponent;
 
 #[derive(Component)]
-struct SeasonText;
+pub struct SeasonText;
+
+#[derive(Component)]
+struct DiversityText;
 
 #[derive(Component)]
 struct NextSeasonButton;
 
+#[derive(Component)]
+struct NextSeasonButtonText;
+
+#[derive(Component)]
+pub struct StatusText;
+
+#[derive(Component)]
+struct SeedPodComponent;
+
+#[derive(Component)]
+struct KeepSeedButton(usize);
+
+#[derive(Component)]
+struct DiscardSeedPodButton;
+
 #[derive(Component)]
 struct RestartButton;
 
+#[derive(Component)]
+struct PauseButton;
+
+#[derive(Component)]
+struct PauseMenuComponent;
+
+#[derive(Component)]
+struct ResumeButton;
+
+#[derive(Component)]
+struct PauseSettingsButton;
+
+#[derive(Component)]
+struct QuitToMenuButton;
+
+#[derive(Component)]
+struct ConfirmationComponent;
+
+#[derive(Component)]
+struct ConfirmButton(ConfirmableAction);
+
+#[derive(Component)]
+struct CancelConfirmationButton;
+
 #[derive(Component)]
 struct HelpButton;
 
@@ -186,6 +455,99 @@ struct CloseHelpButton;
 #[derive(Component)]
 struct HelpScreen;
 
+#[derive(Component)]
+struct SynergiesButton;
+
+#[derive(Component)]
+struct CloseSynergiesButton;
+
+#[derive(Component)]
+struct SynergiesScreen;
+
+#[derive(Component)]
+struct SynergiesText;
+
+#[derive(Component)]
+struct SeasonReportPanel;
+
+#[derive(Component)]
+struct CloseSeasonReportButton;
+
+#[derive(Component)]
+struct SeasonLogButton;
+
+#[derive(Component)]
+struct CloseSeasonLogButton;
+
+#[derive(Component)]
+struct HintButton;
+
+#[derive(Component)]
+struct HintButtonText;
+
+#[derive(Component)]
+struct SeasonLogScreen;
+
+/// The list of season reports on the season log screen.
+#[derive(Component)]
+struct SeasonLogList;
+
+/// A list that can be scrolled with the mouse wheel while it's visible.
+/// Its parent should hide whatever's been scrolled out of view.
+#[derive(Component, Default)]
+struct ScrollingList {
+    position: f32,
+}
+
+/// The reports for every season so far, oldest first.
+#[derive(Default)]
+pub struct SeasonLog(pub Vec<SeasonReport>);
+
+/// Sent when seeds are made by splicing, self-pollinating or taking a cutting.
+/// Spliced and self-pollinated seeds go into the seed pod, so the player may not keep all of them.
+pub struct SeedCreated {
+    pub origin: SeedOrigin,
+    pub count: usize,
+}
+
+/// Sent when a seed is planted in a planter.
+pub struct SeedPlanted {
+    pub planter_id: usize,
+}
+
+/// Sent when a seed grows into a plant.
+pub struct PlantGrew {
+    pub planter_id: usize,
+    pub name: PlantName,
+}
+
+/// Sent when a plant dies.
+pub struct PlantDied {
+    pub name: PlantName,
+    pub cause_of_death: CauseOfDeath,
+}
+
+/// Sent once everything that happens between seasons is done.
+pub struct SeasonAdvanced {
+    pub season: u32,
+}
+
+/// Sent when the player grows a plant that's smart enough.
+pub struct GameWon {
+    pub season: u32,
+}
+
+/// Sent when the player runs out of plants and seeds.
+pub struct GameLost {
+    pub season: u32,
+}
+
+#[derive(Component)]
+enum Highlight {
+    Focus,
+    Selection,
+}
+
 #[derive(Component)]
 struct PlantSpace(usize);
 
@@ -238,15 +600,184 @@ struct Draggable;
 #[derive(Component)]
 struct BeingDragged {
     original_position: Vec3,
+    /// Whether something was done with this when it was dropped, which plays its own sound instead of the drop sound.
+    drop_handled: bool,
 }
 
 pub struct Season(pub u32);
 
-struct SetUp(bool);
+pub struct SetUp(bool);
+
+/// A part of the transition between seasons.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SeasonTransitionStage {
+    /// Moving on to the next season has been asked for, but nothing has happened yet.
+    Starting,
+    /// The seeds that were ready have sprouted, and are growing into plants.
+    Growing,
+    /// The pests have arrived, and are eating the plants they picked.
+    Pests,
+}
+
+/// The transition that plays out when moving on to the next season.
+/// The player can't do anything else until it's finished, except skip it.
+#[derive(Default)]
+pub struct SeasonTransition {
+    stage: Option<SeasonTransitionStage>,
+    timer: Timer,
+    skipping: bool,
+    /// The IDs of the planters with seeds that sprouted this season.
+    sprouted: Vec<usize>,
+    /// The IDs of the planters with plants that are being eaten by pests this season.
+    pest_victims: Vec<usize>,
+    /// What's happened so far this season.
+    report: SeasonReport,
+}
+
+impl SeasonTransition {
+    fn is_running(&self) -> bool {
+        self.stage.is_some()
+    }
+
+    /// Determines whether moving on to the next season has been asked for, but nothing has happened yet.
+    pub fn is_starting(&self) -> bool {
+        self.stage == Some(SeasonTransitionStage::Starting)
+    }
+
+    /// Stops moving on to the next season, as long as nothing has happened yet.
+    pub fn cancel(&mut self) {
+        *self = SeasonTransition::default();
+    }
+
+    /// Starts moving on to the next season, or skips to the end of the transition if it's already started.
+    fn start_or_skip(&mut self) {
+        if self.is_running() {
+            self.skipping = true;
+        } else {
+            self.start();
+        }
+    }
+
+    /// Starts moving on to the next season from the beginning.
+    pub fn start(&mut self) {
+        *self = SeasonTransition {
+            stage: Some(SeasonTransitionStage::Starting),
+            ..default()
+        };
+    }
+}
+
+/// Whether the game is paused, either by the pause menu or by waiting for the player to confirm something.
+struct Paused(bool);
+
+/// Something that loses the player's progress, so they may be asked to confirm it first.
+#[derive(Clone, Copy)]
+enum ConfirmableAction {
+    Restart,
+    QuitToMenu,
+}
 
 pub struct SmartPlant(pub Option<Plant>);
 
-struct BackgroundChannel;
+/// Seeds from the latest splice that the player hasn't picked from yet.
+pub struct SeedPod(pub Vec<Seed>);
+
+/// How many hints the player has asked for this game.
+pub struct HintsUsed(pub u32);
+
+/// Whether plants have male, female, or hermaphrodite flowers that limit which plants can be spliced together.
+pub struct SexedFlowers(pub bool);
+
+/// How plants are drawn, so that players with colour vision deficiencies can tell them apart.
+pub struct PlantDisplaySettings {
+    pub palette: ColorPalette,
+    /// Whether to draw patterns on fruit that show their colour without relying on hue.
+    pub fruit_patterns: bool,
+}
+
+/// The set of colours used to draw stems and fruit.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ColorPalette {
+    Standard,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+}
+
+impl ColorPalette {
+    /// Gets the palette that comes after this one, for cycling through them.
+    pub fn next(&self) -> ColorPalette {
+        match self {
+            ColorPalette::Standard => ColorPalette::Deuteranopia,
+            ColorPalette::Deuteranopia => ColorPalette::Protanopia,
+            ColorPalette::Protanopia => ColorPalette::Tritanopia,
+            ColorPalette::Tritanopia => ColorPalette::Standard,
+        }
+    }
+}
+
+impl std::fmt::Display for ColorPalette {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorPalette::Standard => write!(f, "Standard"),
+            ColorPalette::Deuteranopia => write!(f, "Deuteranopia"),
+            ColorPalette::Protanopia => write!(f, "Protanopia"),
+            ColorPalette::Tritanopia => write!(f, "Tritanopia"),
+        }
+    }
+}
+
+/// A space in the game that can be focused or selected with the keyboard or a gamepad.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FocusTarget {
+    Seed(usize),
+    Planter(usize),
+    PodSeed(usize),
+}
+
+/// Which space the keyboard or gamepad cursor is on, and which space has been selected to act on.
+pub struct KeyboardFocus {
+    focused: Option<FocusTarget>,
+    selected: Option<FocusTarget>,
+}
+
+impl KeyboardFocus {
+    /// Forgets about the space that was selected to act on, if there was one.
+    pub fn clear_selection(&mut self) {
+        self.selected = None;
+    }
+}
+
+#[derive(Clone, Copy)]
+enum FocusDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// The names of the gene interactions that have shown up in any plant so far.
+struct DiscoveredGeneInteractions(Vec<&'static str>);
+
+/// The background track, which the rest of the music's layers build on.
+pub struct BackgroundChannel;
+
+/// The layers of the music that come in as the player gets closer to their goal.
+/// These were all taken from the background track, so they loop in time with it.
+struct MusicBassChannel;
+
+struct MusicPulseChannel;
+
+struct MusicShimmerChannel;
+
+/// How loud each layer of the music currently is, from 0 to 1.
+#[derive(Default)]
+struct MusicMix {
+    background: f64,
+    bass: f64,
+    pulse: f64,
+    shimmer: f64,
+}
 
 pub struct ForegroundChannel;
 
@@ -303,7 +834,21 @@ fn generate_starting_plants() -> Planters {
             Gene::new_with_category(GeneCategory::FruitStyle(FruitStyle::Square)),
             Gene::new_with_category(GeneCategory::FruitColor(FruitColor::Red)),
             Gene::new_with_category(GeneCategory::FruitColor(FruitColor::Purple)),
+            Gene::new_with_category(GeneCategory::Lifespan(Lifespan::Perennial)),
+            Gene::new_with_category(GeneCategory::Lifespan(Lifespan::Annual)),
+            Gene::new_with_category(GeneCategory::Intellect(IntellectGene {
+                locus: 0,
+                allele: IntellectAllele::High,
+            })),
+            Gene::new_with_category(GeneCategory::Intellect(IntellectGene {
+                locus: 0,
+                allele: IntellectAllele::Low,
+            })),
+            Gene::new_with_category(GeneCategory::FlowerType(FlowerType::Male)),
+            Gene::new_with_category(GeneCategory::FlowerType(FlowerType::Hermaphrodite)),
         ],
+        age: 0,
+        inbreeding_penalty: 0,
     };
 
     let plant_2 = Plant {
@@ -317,7 +862,21 @@ fn generate_starting_plants() -> Planters {
             Gene::new_with_category(GeneCategory::FruitStyle(FruitStyle::Triangle)),
             Gene::new_with_category(GeneCategory::FruitColor(FruitColor::Red)),
             Gene::new_with_category(GeneCategory::FruitColor(FruitColor::Yellow)),
+            Gene::new_with_category(GeneCategory::Lifespan(Lifespan::Perennial)),
+            Gene::new_with_category(GeneCategory::Lifespan(Lifespan::Perennial)),
+            Gene::new_with_category(GeneCategory::Intellect(IntellectGene {
+                locus: 1,
+                allele: IntellectAllele::High,
+            })),
+            Gene::new_with_category(GeneCategory::Intellect(IntellectGene {
+                locus: 1,
+                allele: IntellectAllele::Low,
+            })),
+            Gene::new_with_category(GeneCategory::FlowerType(FlowerType::Female)),
+            Gene::new_with_category(GeneCategory::FlowerType(FlowerType::Female)),
         ],
+        age: 0,
+        inbreeding_penalty: 0,
     };
 
     let plant_3 = Plant {
@@ -331,7 +890,21 @@ fn generate_starting_plants() -> Planters {
             Gene::new_with_category(GeneCategory::FruitStyle(FruitStyle::Triangle)),
             Gene::new_with_category(GeneCategory::FruitColor(FruitColor::Purple)),
             Gene::new_with_category(GeneCategory::FruitColor(FruitColor::Yellow)),
+            Gene::new_with_category(GeneCategory::Lifespan(Lifespan::Annual)),
+            Gene::new_with_category(GeneCategory::Lifespan(Lifespan::Perennial)),
+            Gene::new_with_category(GeneCategory::Intellect(IntellectGene {
+                locus: 2,
+                allele: IntellectAllele::High,
+            })),
+            Gene::new_with_category(GeneCategory::Intellect(IntellectGene {
+                locus: 2,
+                allele: IntellectAllele::Low,
+            })),
+            Gene::new_with_category(GeneCategory::FlowerType(FlowerType::Hermaphrodite)),
+            Gene::new_with_category(GeneCategory::FlowerType(FlowerType::Female)),
         ],
+        age: 0,
+        inbreeding_penalty: 0,
     };
 
     Planters(vec![
@@ -342,10 +915,33 @@ fn generate_starting_plants() -> Planters {
     ])
 }
 
-fn is_set_up(set_up: Res<SetUp>) -> ShouldRun {
+pub fn is_set_up(set_up: Res<SetUp>) -> ShouldRun {
     set_up.0.into()
 }
 
+/// Whether the player can do things in the game right now.
+fn is_playing(
+    set_up: Res<SetUp>,
+    paused: Res<Paused>,
+    season_transition: Res<SeasonTransition>,
+    playback: Res<ReplayPlayback>,
+    hot_seat: Res<HotSeat>,
+    lan: Res<Lan>,
+) -> ShouldRun {
+    (set_up.0
+        && !paused.0
+        && !season_transition.is_running()
+        && !playback.is_running()
+        && !hot_seat.is_trading()
+        && !lan.is_between_turns())
+    .into()
+}
+
+/// Whether the game is set up and not in the middle of changing seasons.
+fn is_settled(set_up: Res<SetUp>, season_transition: Res<SeasonTransition>) -> ShouldRun {
+    (set_up.0 && !season_transition.is_running()).into()
+}
+
 #[allow(clippy::too_many_arguments)]
 fn game_setup(
     mut commands: Commands,
@@ -354,6 +950,13 @@ fn game_setup(
     mut planters: ResMut<Planters>,
     mut seeds: ResMut<Seeds>,
     mut smart_plant: ResMut<SmartPlant>,
+    mut discovered_gene_interactions: ResMut<DiscoveredGeneInteractions>,
+    mut seed_pod: ResMut<SeedPod>,
+    mut keyboard_focus: ResMut<KeyboardFocus>,
+    mut paused: ResMut<Paused>,
+    mut season_transition: ResMut<SeasonTransition>,
+    mut season_log: ResMut<SeasonLog>,
+    mut hints_used: ResMut<HintsUsed>,
     mut set_up: ResMut<SetUp>,
     image_assets: Res<ImageAssets>,
 ) {
@@ -365,6 +968,14 @@ fn game_setup(
     *planters = generate_starting_plants();
     *seeds = Seeds(Vec::new());
     smart_plant.0 = None;
+    discovered_gene_interactions.0.clear();
+    seed_pod.0.clear();
+    keyboard_focus.focused = None;
+    keyboard_focus.selected = None;
+    paused.0 = false;
+    *season_transition = SeasonTransition::default();
+    season_log.0.clear();
+    hints_used.0 = 0;
 
     /*
     // background
@@ -527,6 +1138,39 @@ fn game_setup(
             .insert(SeedSpace(i));
     }
 
+    // keyboard and gamepad highlights
+    commands
+        .spawn_bundle(SpriteBundle {
+            sprite: Sprite {
+                color: SELECTION_HIGHLIGHT_COLOR,
+                ..default()
+            },
+            transform: Transform {
+                translation: Vec3::new(0.0, 0.0, MIDDLE_LAYER + 0.5),
+                ..default()
+            },
+            visibility: Visibility { is_visible: false },
+            ..default()
+        })
+        .insert(GameComponent)
+        .insert(Highlight::Selection);
+
+    commands
+        .spawn_bundle(SpriteBundle {
+            sprite: Sprite {
+                color: FOCUS_HIGHLIGHT_COLOR,
+                ..default()
+            },
+            transform: Transform {
+                translation: Vec3::new(0.0, 0.0, MIDDLE_LAYER + 0.6),
+                ..default()
+            },
+            visibility: Visibility { is_visible: false },
+            ..default()
+        })
+        .insert(GameComponent)
+        .insert(Highlight::Focus);
+
     // top bar
     commands
         .spawn_bundle(NodeBundle {
@@ -565,6 +1209,29 @@ fn game_setup(
                 )
                 .insert(SeasonText);
 
+            // genetic diversity display
+            parent
+                .spawn_bundle(
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font: main_font.clone(),
+                            font_size: 25.0,
+                            color: Color::WHITE,
+                        },
+                    )
+                    .with_style(Style {
+                        margin: UiRect::all(Val::Auto),
+                        position_type: PositionType::Absolute,
+                        position: UiRect {
+                            right: Val::Px(215.0),
+                            ..default()
+                        },
+                        ..default()
+                    }),
+                )
+                .insert(DiversityText);
+
             // restart button
             parent
                 .spawn_bundle(ButtonBundle {
@@ -595,6 +1262,36 @@ fn game_setup(
                     ));
                 });
 
+            // pause button
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(150.0), Val::Px(TOP_BAR_HEIGHT * 0.8)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::all(Val::Auto),
+                        position_type: PositionType::Absolute,
+                        position: UiRect {
+                            left: Val::Px(210.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    color: NORMAL_BUTTON.into(),
+                    ..default()
+                })
+                .insert(PauseButton)
+                .with_children(|parent| {
+                    parent.spawn_bundle(TextBundle::from_section(
+                        "Pause",
+                        TextStyle {
+                            font: main_font.clone(),
+                            font_size: 30.0,
+                            color: Color::SEA_GREEN,
+                        },
+                    ));
+                });
+
             // help button
             parent
                 .spawn_bundle(ButtonBundle {
@@ -636,8 +1333,7 @@ fn game_setup(
                     bottom: Val::Px(0.0),
                     ..default()
                 },
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::FlexEnd,
+                align_items: AlignItems::Center,
                 ..default()
             },
             color: BOTTOM_BAR_COLOR.into(),
@@ -645,23 +1341,26 @@ fn game_setup(
         })
         .insert(GameComponent)
         .with_children(|parent| {
-            // next season button
+            // synergies button
             parent
                 .spawn_bundle(ButtonBundle {
                     style: Style {
                         size: Size::new(Val::Px(200.0), Val::Px(BOTTOM_BAR_HEIGHT * 0.8)),
                         justify_content: JustifyContent::Center,
                         align_items: AlignItems::Center,
-                        margin: UiRect::all(Val::Auto),
+                        margin: UiRect {
+                            left: Val::Px(5.0),
+                            ..default()
+                        },
                         ..default()
                     },
                     color: NORMAL_BUTTON.into(),
                     ..default()
                 })
-                .insert(NextSeasonButton)
+                .insert(SynergiesButton)
                 .with_children(|parent| {
                     parent.spawn_bundle(TextBundle::from_section(
-                        "Next Season",
+                        "Synergies",
                         TextStyle {
                             font: main_font.clone(),
                             font_size: 30.0,
@@ -669,15 +1368,132 @@ fn game_setup(
                         },
                     ));
                 });
-        });
 
-    // help screen
-    commands
+            // season log button
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(150.0), Val::Px(BOTTOM_BAR_HEIGHT * 0.8)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect {
+                            left: Val::Px(5.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    color: NORMAL_BUTTON.into(),
+                    ..default()
+                })
+                .insert(SeasonLogButton)
+                .with_children(|parent| {
+                    parent.spawn_bundle(TextBundle::from_section(
+                        "Log",
+                        TextStyle {
+                            font: main_font.clone(),
+                            font_size: 30.0,
+                            color: Color::SEA_GREEN,
+                        },
+                    ));
+                });
+
+            // hint button
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(150.0), Val::Px(BOTTOM_BAR_HEIGHT * 0.8)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect {
+                            left: Val::Px(5.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    color: NORMAL_BUTTON.into(),
+                    ..default()
+                })
+                .insert(HintButton)
+                .with_children(|parent| {
+                    parent
+                        .spawn_bundle(TextBundle::from_section(
+                            "Hint (0)",
+                            TextStyle {
+                                font: main_font.clone(),
+                                font_size: 30.0,
+                                color: Color::SEA_GREEN,
+                            },
+                        ))
+                        .insert(HintButtonText);
+                });
+
+            // status message, in whatever space the buttons leave
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        flex_grow: 1.0,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    color: UiColor(Color::NONE),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn_bundle(TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font: main_font.clone(),
+                                font_size: 25.0,
+                                color: Color::ORANGE,
+                            },
+                        ))
+                        .insert(StatusText);
+                });
+
+            // next season button
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(200.0), Val::Px(BOTTOM_BAR_HEIGHT * 0.8)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect {
+                            right: Val::Px(5.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    color: NORMAL_BUTTON.into(),
+                    ..default()
+                })
+                .insert(NextSeasonButton)
+                .with_children(|parent| {
+                    parent
+                        .spawn_bundle(TextBundle::from_section(
+                            "Next Season",
+                            TextStyle {
+                                font: main_font.clone(),
+                                font_size: 30.0,
+                                color: Color::SEA_GREEN,
+                            },
+                        ))
+                        .insert(NextSeasonButtonText);
+                });
+        });
+
+    // synergies screen
+    commands
         .spawn_bundle(NodeBundle {
             style: Style {
                 size: Size::new(Val::Percent(90.0), Val::Percent(80.0)),
-                position_type: PositionType::Relative,
-                margin: UiRect::all(Val::Auto),
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    left: Val::Percent(5.0),
+                    top: Val::Percent(10.0),
+                    ..default()
+                },
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
                 ..default()
@@ -687,29 +1503,236 @@ fn game_setup(
             ..default()
         })
         .insert(GameComponent)
-        .insert(HelpScreen)
+        .insert(SynergiesScreen)
+        .with_children(|parent| {
+            // synergies text
+            parent
+                .spawn_bundle(
+                    TextBundle::from_section(
+                        get_gene_interactions_text(&[]),
+                        TextStyle {
+                            font: main_font.clone(),
+                            font_size: 30.0,
+                            color: Color::WHITE,
+                        },
+                    )
+                    .with_text_alignment(TextAlignment::CENTER)
+                    .with_style(Style {
+                        margin: UiRect::all(Val::Auto),
+                        max_size: Size {
+                            width: Val::Px(WINDOW_WIDTH * 0.8),
+                            ..default()
+                        },
+                        ..default()
+                    }),
+                )
+                .insert(SynergiesText);
+
+            // close button
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(50.0), Val::Px(50.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::all(Val::Auto),
+                        position_type: PositionType::Absolute,
+                        position: UiRect {
+                            top: Val::Px(5.0),
+                            right: Val::Px(5.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    color: NORMAL_BUTTON.into(),
+                    ..default()
+                })
+                .insert(CloseSynergiesButton)
+                .with_children(|parent| {
+                    parent.spawn_bundle(TextBundle::from_section(
+                        "X",
+                        TextStyle {
+                            font: main_font.clone(),
+                            font_size: 30.0,
+                            color: Color::SEA_GREEN,
+                        },
+                    ));
+                });
+        });
+
+    // season log screen
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(90.0), Val::Percent(80.0)),
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    left: Val::Percent(5.0),
+                    top: Val::Percent(10.0),
+                    ..default()
+                },
+                flex_direction: FlexDirection::ColumnReverse,
+                justify_content: JustifyContent::FlexStart,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            color: Color::rgba(0.1, 0.1, 0.1, 0.99).into(),
+            visibility: Visibility { is_visible: false },
+            ..default()
+        })
+        .insert(GameComponent)
+        .insert(SeasonLogScreen)
         .with_children(|parent| {
-            // help text
+            // title
             parent.spawn_bundle(
                 TextBundle::from_section(
-                    HELP_TEXT,
+                    "Season Log",
                     TextStyle {
-                        font: main_font.clone(),
-                        font_size: 30.0,
+                        font: title_font.clone(),
+                        font_size: 40.0,
                         color: Color::WHITE,
                     },
                 )
-                .with_text_alignment(TextAlignment::CENTER)
                 .with_style(Style {
-                    margin: UiRect::all(Val::Auto),
-                    max_size: Size {
-                        width: Val::Px(WINDOW_WIDTH * 0.8),
-                        ..default()
-                    },
+                    margin: UiRect::all(Val::Px(10.0)),
                     ..default()
                 }),
             );
 
+            // the visible part of the list, which hides whatever's been scrolled out of view
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::ColumnReverse,
+                        flex_grow: 1.0,
+                        size: Size::new(Val::Percent(90.0), Val::Undefined),
+                        margin: UiRect {
+                            bottom: Val::Px(10.0),
+                            ..default()
+                        },
+                        overflow: Overflow::Hidden,
+                        ..default()
+                    },
+                    color: Color::NONE.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn_bundle(NodeBundle {
+                            style: Style {
+                                flex_direction: FlexDirection::ColumnReverse,
+                                flex_grow: 1.0,
+                                max_size: Size::new(Val::Undefined, Val::Undefined),
+                                ..default()
+                            },
+                            color: Color::NONE.into(),
+                            ..default()
+                        })
+                        .insert(SeasonLogList)
+                        .insert(ScrollingList::default())
+                        .with_children(|parent| {
+                            spawn_season_log_entries(parent, &[], &main_font);
+                        });
+                });
+
+            // close button
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(50.0), Val::Px(50.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::all(Val::Auto),
+                        position_type: PositionType::Absolute,
+                        position: UiRect {
+                            top: Val::Px(5.0),
+                            right: Val::Px(5.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    color: NORMAL_BUTTON.into(),
+                    ..default()
+                })
+                .insert(CloseSeasonLogButton)
+                .with_children(|parent| {
+                    parent.spawn_bundle(TextBundle::from_section(
+                        "X",
+                        TextStyle {
+                            font: main_font.clone(),
+                            font_size: 30.0,
+                            color: Color::SEA_GREEN,
+                        },
+                    ));
+                });
+        });
+
+    // help screen
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(90.0), Val::Percent(90.0)),
+                position_type: PositionType::Relative,
+                margin: UiRect::all(Val::Auto),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            color: Color::rgba(0.1, 0.1, 0.1, 0.99).into(),
+            visibility: Visibility { is_visible: false },
+            ..default()
+        })
+        .insert(GameComponent)
+        .insert(HelpScreen)
+        .with_children(|parent| {
+            // the visible part of the help text, which hides whatever's been scrolled out of view
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::ColumnReverse,
+                        size: Size::new(Val::Percent(90.0), Val::Percent(90.0)),
+                        overflow: Overflow::Hidden,
+                        ..default()
+                    },
+                    color: Color::NONE.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn_bundle(NodeBundle {
+                            style: Style {
+                                flex_direction: FlexDirection::ColumnReverse,
+                                flex_grow: 1.0,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            color: Color::NONE.into(),
+                            ..default()
+                        })
+                        .insert(ScrollingList::default())
+                        .with_children(|parent| {
+                            // help text
+                            parent.spawn_bundle(
+                                TextBundle::from_section(
+                                    HELP_TEXT,
+                                    TextStyle {
+                                        font: main_font.clone(),
+                                        font_size: 30.0,
+                                        color: Color::WHITE,
+                                    },
+                                )
+                                .with_text_alignment(TextAlignment::CENTER)
+                                .with_style(Style {
+                                    max_size: Size {
+                                        width: Val::Px(WINDOW_WIDTH * 0.8),
+                                        ..default()
+                                    },
+                                    ..default()
+                                }),
+                            );
+                        });
+                });
+
             // close button
             parent
                 .spawn_bundle(ButtonBundle {
@@ -745,114 +1768,1506 @@ fn game_setup(
     set_up.0 = true;
 }
 
-fn start_background_music(
-    audio_assets: Res<AudioAssets>,
-    audio: Res<AudioChannel<BackgroundChannel>>,
+/// Gets ready to record the game that's starting, or to play back the replay that's been picked.
+/// This seeds the random number generator before the game is set up, so that every splice,
+/// plant name and pest attack after that comes out the same when the replay is played back.
+fn replay_setup(
+    mut replay: ResMut<Replay>,
+    mut playback: ResMut<ReplayPlayback>,
+    mut sexed_flowers: ResMut<SexedFlowers>,
+    mut versus_mode: ResMut<VersusMode>,
+    mut lan: ResMut<Lan>,
 ) {
-    audio
-        .play(audio_assets.background_music.clone())
-        .fade_in(AudioTween::new(
-            Duration::from_secs(3),
-            AudioEasing::OutPowi(2),
-        ))
-        .with_volume(0.33)
-        .looped();
-}
+    // a LAN match that's just been started by the host is set up from the seed everyone's been sent
+    let new_lan_match = lan
+        .lan_match
+        .as_mut()
+        .filter(|lan_match| !lan_match.started && playback.replay.is_none());
+
+    *replay = match (&playback.replay, new_lan_match) {
+        (Some(playback_replay), _) => {
+            sexed_flowers.0 = playback_replay.sexed_flowers;
+            versus_mode.0 = playback_replay.versus;
+            Replay {
+                seed: playback_replay.seed,
+                sexed_flowers: playback_replay.sexed_flowers,
+                versus: playback_replay.versus,
+                actions: Vec::new(),
+            }
+        }
+        (None, Some(lan_match)) => {
+            lan_match.started = true;
+            sexed_flowers.0 = lan_match.sexed_flowers;
+            versus_mode.0 = false;
+            Replay {
+                seed: lan_match.seed,
+                sexed_flowers: lan_match.sexed_flowers,
+                versus: false,
+                actions: Vec::new(),
+            }
+        }
+        (None, None) => Replay {
+            seed: rand::random(),
+            sexed_flowers: sexed_flowers.0,
+            versus: versus_mode.0,
+            actions: Vec::new(),
+        },
+    };
 
-fn stop_background_music(audio: Res<AudioChannel<BackgroundChannel>>) {
-    audio.stop();
-}
+    if !lan.is_playing() {
+        lan.lan_match = None;
+    }
+    seed_rng(replay.seed);
 
-type InteractedNextSeasonButtonTuple = (Changed<Interaction>, With<NextSeasonButton>);
+    playback.next_action = 0;
+    playback.timer = Timer::from_seconds(REPLAY_STEP_DURATION, false);
+}
 
-/// Handles interactions with the next season button.
+/// Does the next thing from the replay that's being watched, once whatever happened last has finished.
+/// Everything that's played back is recorded again, so the player can carry on from the end of the replay.
+#[allow(clippy::too_many_arguments)]
+fn replay_playback_system(
+    time: Res<Time>,
+    paused: Res<Paused>,
+    mut playback: ResMut<ReplayPlayback>,
+    mut replay: ResMut<Replay>,
+    mut season_transition: ResMut<SeasonTransition>,
+    mut planters: ResMut<Planters>,
+    mut seeds: ResMut<Seeds>,
+    mut seed_pod: ResMut<SeedPod>,
+    sexed_flowers: Res<SexedFlowers>,
+    mut status_text_query: Query<&mut Text, StatusTextFilter>,
+    sound_effects: SoundEffects,
+    mut seed_created_events: EventWriter<SeedCreated>,
+    mut seed_planted_events: EventWriter<SeedPlanted>,
+) {
+    if !playback.is_running() || paused.0 || season_transition.is_running() {
+        return;
+    }
+
+    playback.timer.tick(time.delta());
+    if !playback.timer.finished() {
+        return;
+    }
+    playback.timer.reset();
+
+    let next_action = playback
+        .replay
+        .as_ref()
+        .and_then(|playback_replay| playback_replay.actions.get(playback.next_action))
+        .copied();
+    let action = match next_action {
+        Some(action) => action,
+        None => {
+            playback.replay = None;
+            show_status_message(
+                &mut status_text_query,
+                "That's the end of the replay, so it's over to you!".to_string(),
+            );
+            return;
+        }
+    };
+    playback.next_action += 1;
+
+    match action {
+        ReplayAction::MakeSeeds {
+            pollen_planter,
+            pistil_planter,
+            origin,
+        } => {
+            if let (Some(Planter::Plant(plant_1)), Some(Planter::Plant(plant_2))) = (
+                planters.with_id(pollen_planter),
+                planters.with_id(pistil_planter),
+            ) {
+                let made_seeds = make_seeds(
+                    plant_1,
+                    plant_2,
+                    origin,
+                    &sexed_flowers,
+                    &mut seeds,
+                    &mut seed_pod,
+                    &mut status_text_query,
+                    &mut seed_created_events,
+                );
+                play_splice_sound(made_seeds, &sound_effects);
+            }
+        }
+        ReplayAction::KeepSeed(index) => {
+            keep_seed_from_pod(index, &mut seeds, &mut seed_pod, &mut status_text_query);
+        }
+        ReplayAction::DiscardSeedPod => seed_pod.0.clear(),
+        ReplayAction::PlantSeed { seed, planter } => {
+            if planter < planters.0.len() {
+                if let Some(seed) = seeds.take_with_id(seed) {
+                    planters.0[planter] = Planter::Seed(seed);
+                    sound_effects.play(SoundEffect::Plant);
+                    seed_planted_events.send(SeedPlanted {
+                        planter_id: planter,
+                    });
+                }
+            }
+        }
+        ReplayAction::MovePlant { from, to, swap } => {
+            if move_plant(&mut planters, from, to, swap) {
+                sound_effects.play(SoundEffect::Drop);
+            }
+        }
+        ReplayAction::NextSeason => season_transition.start_or_skip(),
+    }
+
+    // moving on to the next season is recorded when the season starts changing
+    if !matches!(action, ReplayAction::NextSeason) {
+        replay.actions.push(action);
+    }
+}
+
+/// Starts every layer of the music at once.
+/// The bass, pulse and shimmer layers were filtered out of the background track and are the same length as it,
+/// so as long as they all start together they stay in time with each other.
+/// They all start silent, and `music_mix_system` fades in whichever ones should be heard.
+fn start_background_music(
+    audio_assets: Res<AudioAssets>,
+    background_audio: Res<AudioChannel<BackgroundChannel>>,
+    bass_audio: Res<AudioChannel<MusicBassChannel>>,
+    pulse_audio: Res<AudioChannel<MusicPulseChannel>>,
+    shimmer_audio: Res<AudioChannel<MusicShimmerChannel>>,
+    mut music_mix: ResMut<MusicMix>,
+) {
+    *music_mix = MusicMix::default();
+
+    background_audio
+        .play(audio_assets.background_music.clone())
+        .with_volume(0.0)
+        .looped();
+    bass_audio
+        .play(audio_assets.music_bass.clone())
+        .with_volume(0.0)
+        .looped();
+    pulse_audio
+        .play(audio_assets.music_pulse.clone())
+        .with_volume(0.0)
+        .looped();
+    shimmer_audio
+        .play(audio_assets.music_shimmer.clone())
+        .with_volume(0.0)
+        .looped();
+}
+
+/// Fades the music out rather than cutting it off, so it crossfades into whatever plays next.
+fn stop_background_music(
+    background_audio: Res<AudioChannel<BackgroundChannel>>,
+    bass_audio: Res<AudioChannel<MusicBassChannel>>,
+    pulse_audio: Res<AudioChannel<MusicPulseChannel>>,
+    shimmer_audio: Res<AudioChannel<MusicShimmerChannel>>,
+) {
+    let fade_out = || AudioTween::new(MUSIC_FADE_OUT_DURATION, AudioEasing::InPowi(2));
+
+    background_audio.stop().fade_out(fade_out());
+    bass_audio.stop().fade_out(fade_out());
+    pulse_audio.stop().fade_out(fade_out());
+    shimmer_audio.stop().fade_out(fade_out());
+}
+
+/// Brings the music's layers in and out to match how the game is going.
+/// The background track always plays, and the other layers build on it as the smartest plant gets closer to the goal,
+/// with the busier layers thinning out when there are fewer plants alive.
+#[allow(clippy::too_many_arguments)]
+fn music_mix_system(
+    time: Res<Time>,
+    planters: Res<Planters>,
+    settings: Res<Settings>,
+    mut music_mix: ResMut<MusicMix>,
+    background_audio: Res<AudioChannel<BackgroundChannel>>,
+    bass_audio: Res<AudioChannel<MusicBassChannel>>,
+    pulse_audio: Res<AudioChannel<MusicPulseChannel>>,
+    shimmer_audio: Res<AudioChannel<MusicShimmerChannel>>,
+) {
+    let living_plants = planters
+        .0
+        .iter()
+        .filter_map(|planter| match planter {
+            Planter::Plant(plant) => Some(plant),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let best_intelligence = living_plants
+        .iter()
+        .map(|plant| plant.get_phenotype().intelligence)
+        .max()
+        .unwrap_or(0);
+
+    let progress = (best_intelligence as f64 / GOAL_INTELLIGENCE as f64).clamp(0.0, 1.0);
+    let fullness = living_plants.len() as f64 / NUM_PLANT_SPACES as f64;
+    let tension = ((progress - 0.3) / 0.3).clamp(0.0, 1.0);
+
+    let target = MusicMix {
+        background: 1.0,
+        bass: tension * MUSIC_LAYER_LEVEL,
+        pulse: tension * fullness * MUSIC_LAYER_LEVEL,
+        shimmer: ((progress - 0.6) / 0.3).clamp(0.0, 1.0) * fullness * MUSIC_LAYER_LEVEL,
+    };
+
+    let max_step = MUSIC_FADE_SPEED * time.delta_seconds_f64();
+    let volume = BACKGROUND_MUSIC_VOLUME * settings.music_volume;
+
+    fade_music_layer(
+        &mut music_mix.background,
+        target.background,
+        max_step,
+        volume,
+        settings.is_changed(),
+        &background_audio,
+    );
+    fade_music_layer(
+        &mut music_mix.bass,
+        target.bass,
+        max_step,
+        volume,
+        settings.is_changed(),
+        &bass_audio,
+    );
+    fade_music_layer(
+        &mut music_mix.pulse,
+        target.pulse,
+        max_step,
+        volume,
+        settings.is_changed(),
+        &pulse_audio,
+    );
+    fade_music_layer(
+        &mut music_mix.shimmer,
+        target.shimmer,
+        max_step,
+        volume,
+        settings.is_changed(),
+        &shimmer_audio,
+    );
+}
+
+/// Moves a layer of the music towards the level it should be at, updating its channel if anything changed.
+fn fade_music_layer<T: Resource>(
+    level: &mut f64,
+    target: f64,
+    max_step: f64,
+    volume: f64,
+    volume_changed: bool,
+    audio: &AudioChannel<T>,
+) {
+    let new_level = *level + (target - *level).clamp(-max_step, max_step);
+
+    if new_level != *level || volume_changed {
+        *level = new_level;
+        audio.set_volume(volume * new_level);
+    }
+}
+
+type InteractedNextSeasonButtonTuple = (Changed<Interaction>, With<NextSeasonButton>);
+
+/// Handles interactions with the next season button.
 fn next_season_button_system(
+    interaction_query: Query<&Interaction, InteractedNextSeasonButtonTuple>,
+    paused: Res<Paused>,
+    playback: Res<ReplayPlayback>,
+    mut season_transition: ResMut<SeasonTransition>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked && !paused.0 {
+            // while watching a replay, the seasons only change when the replay says so
+            if playback.is_running() && !season_transition.is_running() {
+                return;
+            }
+
+            season_transition.start_or_skip();
+        }
+    }
+}
+
+/// Turns the next season button into a skip button while the season is changing.
+fn next_season_button_text_system(
+    season_transition: Res<SeasonTransition>,
+    hot_seat: Res<HotSeat>,
+    lan: Res<Lan>,
+    mut text_query: Query<&mut Text, With<NextSeasonButtonText>>,
+) {
+    if !season_transition.is_changed() && !hot_seat.is_changed() && !lan.is_changed() {
+        return;
+    }
+
+    let button_text = if season_transition.is_running() {
+        "Skip"
+    } else if hot_seat.next_player_this_season().is_some() || lan.is_playing() {
+        "End Turn"
+    } else {
+        "Next Season"
+    };
+
+    for mut text in text_query.iter_mut() {
+        if text.sections[0].value != button_text {
+            text.sections[0].value = button_text.to_string();
+        }
+    }
+}
+
+/// Lets the player skip the transition between seasons with the same keys that start it.
+fn skip_season_transition_system(
+    keyboard: Res<Input<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    paused: Res<Paused>,
+    mut season_transition: ResMut<SeasonTransition>,
+) {
+    if !season_transition.is_running() || paused.0 {
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::N)
+        || gamepad_just_pressed(&gamepads, &gamepad_buttons, GamepadButtonType::Start)
+    {
+        season_transition.skipping = true;
+    }
+}
+
+/// Moves on to the next season one step at a time, so the player can see the seeds sprout
+/// and then the pests arrive. If the transition is being skipped, every step happens at once.
+#[allow(clippy::too_many_arguments)]
+fn season_transition_system(
+    time: Res<Time>,
+    paused: Res<Paused>,
+    mut season_transition: ResMut<SeasonTransition>,
     mut season: ResMut<Season>,
     mut season_text_query: Query<&mut Text, With<SeasonText>>,
-    interaction_query: Query<&Interaction, InteractedNextSeasonButtonTuple>,
+    mut status_text_query: Query<&mut Text, StatusTextFilter>,
     mut planters: ResMut<Planters>,
+    mut season_log: ResMut<SeasonLog>,
+    mut replay: ResMut<Replay>,
+    mut rival: ResMut<Rival>,
+    sexed_flowers: Res<SexedFlowers>,
+    sound_effects: SoundEffects,
+    mut plant_grew_events: EventWriter<PlantGrew>,
+    mut plant_died_events: EventWriter<PlantDied>,
+    mut season_advanced_events: EventWriter<SeasonAdvanced>,
+) {
+    if !season_transition.is_running() || paused.0 {
+        return;
+    }
+
+    season_transition.timer.tick(time.delta());
+
+    loop {
+        let waiting = !season_transition.skipping && !season_transition.timer.finished();
+
+        match season_transition.stage {
+            Some(SeasonTransitionStage::Starting) => {
+                replay.actions.push(ReplayAction::NextSeason);
+                increment_season(&mut season, &mut season_text_query);
+
+                // the rival moves on to the next season at the same time, if there is one
+                rival.play_season(season.0, sexed_flowers.0);
+
+                let planted_seeds = planters
+                    .0
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, planter)| matches!(planter, Planter::Seed(_)))
+                    .map(|(id, _)| id)
+                    .collect::<Vec<_>>();
+
+                season_transition.report = SeasonReport {
+                    season: season.0,
+                    ..planters.grow()
+                };
+
+                season_transition.sprouted = planted_seeds
+                    .into_iter()
+                    .filter(|id| matches!(planters.with_id(*id), Some(Planter::Plant(_))))
+                    .collect();
+
+                for &planter_id in &season_transition.sprouted {
+                    if let Some(Planter::Plant(plant)) = planters.with_id(planter_id) {
+                        plant_grew_events.send(PlantGrew {
+                            planter_id,
+                            name: plant.name.clone(),
+                        });
+                    }
+                }
+                // the only plants that have died so far are the ones that got too old
+                send_plant_died_events(&season_transition.report.deaths, &mut plant_died_events);
+
+                sound_effects.play(SoundEffect::NextSeason);
+                show_status_message(&mut status_text_query, String::new());
+
+                season_transition.stage = Some(SeasonTransitionStage::Growing);
+                season_transition.timer = Timer::from_seconds(GROWING_DURATION, false);
+            }
+            Some(SeasonTransitionStage::Growing) if !waiting => {
+                season_transition.pest_victims = planters.choose_pest_victims();
+
+                if season_transition.pest_victims.is_empty() {
+                    finish_season_transition(
+                        &mut season_transition,
+                        &planters,
+                        &mut season_log,
+                        &mut status_text_query,
+                        &mut season_advanced_events,
+                    );
+                } else {
+                    sound_effects.play(SoundEffect::EatenByPests);
+                    season_transition.stage = Some(SeasonTransitionStage::Pests);
+                    season_transition.timer = Timer::from_seconds(PESTS_DURATION, false);
+                }
+            }
+            Some(SeasonTransitionStage::Pests) if !waiting => {
+                let SeasonTransition {
+                    pest_victims,
+                    report,
+                    ..
+                } = &mut *season_transition;
+                let deaths_before = report.deaths.len();
+                planters.unleash_pests(pest_victims, report);
+                send_plant_died_events(&report.deaths[deaths_before..], &mut plant_died_events);
+
+                finish_season_transition(
+                    &mut season_transition,
+                    &planters,
+                    &mut season_log,
+                    &mut status_text_query,
+                    &mut season_advanced_events,
+                );
+            }
+            _ => break,
+        }
+    }
+}
+
+/// Ends the transition between seasons, and adds what happened to the season log.
+fn finish_season_transition(
+    season_transition: &mut SeasonTransition,
+    planters: &Planters,
+    season_log: &mut SeasonLog,
+    status_text_query: &mut Query<&mut Text, StatusTextFilter>,
+    season_advanced_events: &mut EventWriter<SeasonAdvanced>,
+) {
+    season_transition.stage = None;
+    season_transition.skipping = false;
+
+    let mut report = std::mem::take(&mut season_transition.report);
+    report.stats = planters.get_phenotype_stats();
+
+    let status_message = match report.failed_seeds {
+        0 => String::new(),
+        1 => "A seed failed to sprout!".to_string(),
+        x => format!("{x} seeds failed to sprout!"),
+    };
+    show_status_message(status_text_query, status_message);
+
+    season_advanced_events.send(SeasonAdvanced {
+        season: report.season,
+    });
+    season_log.0.push(report);
+}
+
+fn send_plant_died_events(
+    deaths: &[(PlantName, CauseOfDeath)],
+    plant_died_events: &mut EventWriter<PlantDied>,
+) {
+    for (name, cause_of_death) in deaths {
+        plant_died_events.send(PlantDied {
+            name: name.clone(),
+            cause_of_death: *cause_of_death,
+        });
+    }
+}
+
+/// Makes sprouting plants grow to full size, and shakes the plants that pests are eating.
+/// This runs after the plant images have been respawned for the new season,
+/// so they never show up at full size for a frame before they start growing.
+fn season_transition_animation_system(
+    time: Res<Time>,
+    season_transition: Res<SeasonTransition>,
+    mut plant_images_query: Query<(&PlantImage, &mut Transform)>,
+) {
+    for (plant_image, mut transform) in plant_images_query.iter_mut() {
+        let (scale, angle) = match season_transition.stage {
+            Some(SeasonTransitionStage::Growing)
+                if season_transition.sprouted.contains(&plant_image.0) =>
+            {
+                // ease out, so the plants shoot up quickly then settle into their full size
+                let progress = season_transition.timer.percent();
+                let eased_progress = 1.0 - (1.0 - progress).powi(2);
+                (
+                    SPROUT_START_SCALE + (1.0 - SPROUT_START_SCALE) * eased_progress,
+                    0.0,
+                )
+            }
+            Some(SeasonTransitionStage::Pests)
+                if season_transition.pest_victims.contains(&plant_image.0) =>
+            {
+                let shake = (time.seconds_since_startup() as f32 * PEST_SHAKE_SPEED).sin();
+                (1.0, shake * PEST_SHAKE_ANGLE)
+            }
+            _ => (1.0, 0.0),
+        };
+
+        let rotation = Quat::from_rotation_z(angle);
+        if transform.scale.x != scale || transform.rotation != rotation {
+            transform.scale = Vec3::new(scale, scale, 1.0);
+            transform.rotation = rotation;
+        }
+    }
+}
+
+pub type StatusTextFilter = (With<StatusText>, Without<SeasonText>);
+
+/// Shows the provided message in the bottom bar, replacing any message that was already there.
+pub fn show_status_message(
+    status_text_query: &mut Query<&mut Text, StatusTextFilter>,
+    message: String,
+) {
+    for mut status_text in status_text_query.iter_mut() {
+        status_text.sections[0].value = message.clone();
+    }
+}
+
+fn increment_season(
+    season: &mut ResMut<Season>,
+    season_text_query: &mut Query<&mut Text, With<SeasonText>>,
+) {
+    season.0 += 1;
+
+    for mut season_text in season_text_query.iter_mut() {
+        season_text.sections[0].value = format!("Season {}", season.0);
+    }
+}
+
+type InteractedRestartButtonTuple = (Changed<Interaction>, With<RestartButton>);
+
+/// Handles interactions with the restart button.
+fn restart_button_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    mut paused: ResMut<Paused>,
+    mut game_state: ResMut<State<GameState>>,
+    mut set_up: ResMut<SetUp>,
+    interaction_query: Query<&Interaction, InteractedRestartButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            request_action(
+                ConfirmableAction::Restart,
+                &mut commands,
+                &asset_server,
+                &settings,
+                &mut paused,
+                &mut set_up,
+                &mut game_state,
+            );
+        }
+    }
+}
+
+/// Does the provided action, or asks the player to confirm it first if they want to be asked.
+fn request_action(
+    action: ConfirmableAction,
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    settings: &Settings,
+    paused: &mut Paused,
+    set_up: &mut SetUp,
+    game_state: &mut State<GameState>,
+) {
+    if settings.confirmations {
+        paused.0 = true;
+        spawn_confirmation(action, commands, asset_server);
+    } else {
+        perform_action(action, set_up, game_state);
+    }
+}
+
+fn perform_action(
+    action: ConfirmableAction,
+    set_up: &mut SetUp,
+    game_state: &mut State<GameState>,
+) {
+    set_up.0 = false;
+    let next_state = match action {
+        ConfirmableAction::Restart => GameState::GameLoading,
+        ConfirmableAction::QuitToMenu => GameState::Menu,
+    };
+    game_state.set(next_state).unwrap();
+}
+
+/// Shows a dialog asking the player whether they really want to do the provided action.
+fn spawn_confirmation(
+    action: ConfirmableAction,
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+) {
+    let main_font = asset_server.load(MAIN_FONT);
+
+    let question = match action {
+        ConfirmableAction::Restart => "Are you sure you want to restart?",
+        ConfirmableAction::QuitToMenu => "Are you sure you want to quit to the menu?",
+    };
+
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::ColumnReverse,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            color: Color::rgba(0.0, 0.0, 0.0, 0.8).into(),
+            ..default()
+        })
+        .insert(GameComponent)
+        .insert(ConfirmationComponent)
+        .with_children(|parent| {
+            parent.spawn_bundle(
+                TextBundle::from_section(
+                    format!("{question}\nYour plants and seeds will be lost."),
+                    TextStyle {
+                        font: main_font.clone(),
+                        font_size: 35.0,
+                        color: Color::WHITE,
+                    },
+                )
+                .with_text_alignment(TextAlignment::CENTER)
+                .with_style(Style {
+                    margin: UiRect {
+                        bottom: Val::Px(20.0),
+                        ..default()
+                    },
+                    ..default()
+                }),
+            );
+
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    color: UiColor(Color::NONE),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    spawn_menu_button(parent, "Yes", ConfirmButton(action), &main_font);
+                    spawn_menu_button(parent, "No", CancelConfirmationButton, &main_font);
+                });
+        });
+}
+
+/// Spawns a button for the pause menu or a confirmation dialog.
+fn spawn_menu_button(
+    parent: &mut ChildBuilder,
+    text: &str,
+    component: impl Component,
+    font: &Handle<Font>,
+) {
+    parent
+        .spawn_bundle(ButtonBundle {
+            style: Style {
+                size: Size::new(Val::Px(250.0), Val::Px(50.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            color: NORMAL_BUTTON.into(),
+            ..default()
+        })
+        .insert(component)
+        .with_children(|parent| {
+            parent.spawn_bundle(TextBundle::from_section(
+                text,
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 30.0,
+                    color: Color::SEA_GREEN,
+                },
+            ));
+        });
+}
+
+type InteractedConfirmButtonTuple = (Changed<Interaction>, With<ConfirmButton>);
+
+/// Handles interactions with the button for confirming an action.
+fn confirm_button_system(
+    mut game_state: ResMut<State<GameState>>,
+    mut set_up: ResMut<SetUp>,
+    interaction_query: Query<(&Interaction, &ConfirmButton), InteractedConfirmButtonTuple>,
+) {
+    for (interaction, confirm_button) in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            perform_action(confirm_button.0, &mut set_up, &mut game_state);
+        }
+    }
+}
+
+type InteractedCancelConfirmationButtonTuple =
+    (Changed<Interaction>, With<CancelConfirmationButton>);
+
+/// Handles interactions with the button for backing out of an action.
+fn cancel_confirmation_button_system(
+    mut commands: Commands,
+    mut paused: ResMut<Paused>,
+    confirmation_query: Query<Entity, With<ConfirmationComponent>>,
+    pause_menu_query: Query<Entity, With<PauseMenuComponent>>,
+    interaction_query: Query<&Interaction, InteractedCancelConfirmationButtonTuple>,
 ) {
     for interaction in interaction_query.iter() {
         if *interaction == Interaction::Clicked {
-            increment_season(&mut season, &mut season_text_query);
-            planters.next_season();
+            despawn_components(confirmation_query, &mut commands);
+            // go back to the pause menu if that's where the action came from
+            paused.0 = !pause_menu_query.is_empty();
+            return;
         }
     }
 }
 
-fn increment_season(
-    season: &mut ResMut<Season>,
-    season_text_query: &mut Query<&mut Text, With<SeasonText>>,
+/// Shows the pause menu if the game is paused.
+fn pause_menu_setup(mut commands: Commands, asset_server: Res<AssetServer>, paused: Res<Paused>) {
+    if paused.0 {
+        spawn_pause_menu(&mut commands, &asset_server);
+    }
+}
+
+fn spawn_pause_menu(commands: &mut Commands, asset_server: &AssetServer) {
+    let title_font = asset_server.load(TITLE_FONT);
+    let main_font = asset_server.load(MAIN_FONT);
+
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::ColumnReverse,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            color: Color::rgba(0.0, 0.0, 0.0, 0.8).into(),
+            ..default()
+        })
+        .insert(GameComponent)
+        .insert(PauseMenuComponent)
+        .with_children(|parent| {
+            parent.spawn_bundle(
+                TextBundle::from_section(
+                    "Paused",
+                    TextStyle {
+                        font: title_font.clone(),
+                        font_size: 60.0,
+                        color: Color::WHITE,
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect {
+                        bottom: Val::Px(20.0),
+                        ..default()
+                    },
+                    ..default()
+                }),
+            );
+
+            spawn_menu_button(parent, "Resume", ResumeButton, &main_font);
+            spawn_menu_button(parent, "Settings", PauseSettingsButton, &main_font);
+            spawn_menu_button(parent, "Quit to menu", QuitToMenuButton, &main_font);
+        });
+}
+
+/// Pauses or unpauses the game, showing or hiding the pause menu.
+fn set_paused(
+    pause: bool,
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    paused: &mut Paused,
+    pause_menu_query: Query<Entity, With<PauseMenuComponent>>,
+) {
+    paused.0 = pause;
+    despawn_components(pause_menu_query, commands);
+    if pause {
+        spawn_pause_menu(commands, asset_server);
+    }
+}
+
+type InteractedPauseButtonTuple = (Changed<Interaction>, With<PauseButton>);
+
+/// Handles interactions with the pause button.
+fn pause_button_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut paused: ResMut<Paused>,
+    pause_menu_query: Query<Entity, With<PauseMenuComponent>>,
+    interaction_query: Query<&Interaction, InteractedPauseButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            set_paused(
+                true,
+                &mut commands,
+                &asset_server,
+                &mut paused,
+                pause_menu_query,
+            );
+            return;
+        }
+    }
+}
+
+/// Handles pausing and unpausing the game with the escape key.
+#[allow(clippy::too_many_arguments)]
+fn pause_key_system(
+    mut commands: Commands,
+    keyboard: Res<Input<KeyCode>>,
+    asset_server: Res<AssetServer>,
+    keyboard_focus: Res<KeyboardFocus>,
+    seed_pod: Res<SeedPod>,
+    mut paused: ResMut<Paused>,
+    confirmation_query: Query<Entity, With<ConfirmationComponent>>,
+    pause_menu_query: Query<Entity, With<PauseMenuComponent>>,
+) {
+    if !keyboard.just_pressed(KeyCode::Escape) || !confirmation_query.is_empty() {
+        return;
+    }
+
+    if paused.0 {
+        set_paused(
+            false,
+            &mut commands,
+            &asset_server,
+            &mut paused,
+            pause_menu_query,
+        );
+    } else if keyboard_focus.selected.is_none() && seed_pod.0.is_empty() {
+        // otherwise escape just puts down whatever's selected, or discards the seed pod
+        set_paused(
+            true,
+            &mut commands,
+            &asset_server,
+            &mut paused,
+            pause_menu_query,
+        );
+    }
+}
+
+type InteractedResumeButtonTuple = (Changed<Interaction>, With<ResumeButton>);
+
+/// Handles interactions with the resume button.
+fn resume_button_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut paused: ResMut<Paused>,
+    pause_menu_query: Query<Entity, With<PauseMenuComponent>>,
+    interaction_query: Query<&Interaction, InteractedResumeButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            set_paused(
+                false,
+                &mut commands,
+                &asset_server,
+                &mut paused,
+                pause_menu_query,
+            );
+            return;
+        }
+    }
+}
+
+type InteractedPauseSettingsButtonTuple = (Changed<Interaction>, With<PauseSettingsButton>);
+
+/// Handles interactions with the settings button in the pause menu.
+fn pause_settings_button_system(
+    mut game_state: ResMut<State<GameState>>,
+    interaction_query: Query<&Interaction, InteractedPauseSettingsButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            // pushing the settings screen on top of the game keeps the game around for when it's closed
+            game_state.push(GameState::Settings).unwrap();
+        }
+    }
+}
+
+type InteractedQuitToMenuButtonTuple = (Changed<Interaction>, With<QuitToMenuButton>);
+
+/// Handles interactions with the quit to menu button.
+fn quit_to_menu_button_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    mut paused: ResMut<Paused>,
+    mut game_state: ResMut<State<GameState>>,
+    mut set_up: ResMut<SetUp>,
+    interaction_query: Query<&Interaction, InteractedQuitToMenuButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            request_action(
+                ConfirmableAction::QuitToMenu,
+                &mut commands,
+                &asset_server,
+                &settings,
+                &mut paused,
+                &mut set_up,
+                &mut game_state,
+            );
+        }
+    }
+}
+
+type InteractedHelpButtonTuple = (Changed<Interaction>, With<HelpButton>);
+
+/// Handles interactions with the help button
+fn help_button_system(
+    mut help_screen_query: Query<&mut Visibility, With<HelpScreen>>,
+    interaction_query: Query<&Interaction, InteractedHelpButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            for mut visibility in help_screen_query.iter_mut() {
+                visibility.is_visible = true;
+            }
+        }
+    }
+}
+
+type InteractedCloseHelpButtonTuple = (Changed<Interaction>, With<CloseHelpButton>);
+
+/// Handles interactions with the close help button
+fn close_help_button_system(
+    mut help_screen_query: Query<&mut Visibility, With<HelpScreen>>,
+    interaction_query: Query<&Interaction, InteractedCloseHelpButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            for mut visibility in help_screen_query.iter_mut() {
+                visibility.is_visible = false;
+            }
+        }
+    }
+}
+
+type InteractedSynergiesButtonTuple = (Changed<Interaction>, With<SynergiesButton>);
+
+/// Handles interactions with the synergies button
+fn synergies_button_system(
+    mut synergies_screen_query: Query<&mut Visibility, With<SynergiesScreen>>,
+    interaction_query: Query<&Interaction, InteractedSynergiesButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            for mut visibility in synergies_screen_query.iter_mut() {
+                visibility.is_visible = true;
+            }
+        }
+    }
+}
+
+type InteractedCloseSynergiesButtonTuple = (Changed<Interaction>, With<CloseSynergiesButton>);
+
+/// Handles interactions with the close synergies button
+fn close_synergies_button_system(
+    mut synergies_screen_query: Query<&mut Visibility, With<SynergiesScreen>>,
+    interaction_query: Query<&Interaction, InteractedCloseSynergiesButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            for mut visibility in synergies_screen_query.iter_mut() {
+                visibility.is_visible = false;
+            }
+        }
+    }
+}
+
+type InteractedSeasonLogButtonTuple = (Changed<Interaction>, With<SeasonLogButton>);
+
+/// Handles interactions with the season log buttons
+fn season_log_button_system(
+    mut season_log_screen_query: Query<&mut Visibility, With<SeasonLogScreen>>,
+    interaction_query: Query<&Interaction, InteractedSeasonLogButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            for mut visibility in season_log_screen_query.iter_mut() {
+                visibility.is_visible = true;
+            }
+        }
+    }
+}
+
+type InteractedHintButtonTuple = (Changed<Interaction>, With<HintButton>);
+
+/// Handles interactions with the hint button
+fn hint_button_system(
+    planters: Res<Planters>,
+    seeds: Res<Seeds>,
+    seed_pod: Res<SeedPod>,
+    sexed_flowers: Res<SexedFlowers>,
+    mut hints_used: ResMut<HintsUsed>,
+    mut status_text_query: Query<&mut Text, StatusTextFilter>,
+    interaction_query: Query<&Interaction, InteractedHintButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            let hint = find_hint(&planters, &seeds, &seed_pod.0, sexed_flowers.0);
+            hints_used.0 += 1;
+            show_status_message(&mut status_text_query, get_hint_text(&hint, &planters));
+        }
+    }
+}
+
+/// Keeps the count of hints used on the hint button up to date.
+fn hint_button_text_system(
+    hints_used: Res<HintsUsed>,
+    mut text_query: Query<&mut Text, With<HintButtonText>>,
+) {
+    if !hints_used.is_changed() {
+        return;
+    }
+
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = format!("Hint ({})", hints_used.0);
+    }
+}
+
+/// Gets the text that explains the provided hint to the player.
+fn get_hint_text(hint: &Hint, planters: &Planters) -> String {
+    let plant_name = |planter_id: usize| match planters.with_id(planter_id) {
+        Some(Planter::Plant(plant)) => plant.name.to_string(),
+        _ => format!("planter {}", planter_id + 1),
+    };
+
+    match hint {
+        Hint::MakeSeeds {
+            pollen_planter,
+            pistil_planter,
+            origin,
+            outlook,
+        } => {
+            let action = match origin {
+                SeedOrigin::Spliced => format!(
+                    "Splice {} onto {}",
+                    plant_name(*pollen_planter),
+                    plant_name(*pistil_planter)
+                ),
+                SeedOrigin::SelfPollinated => {
+                    format!("Self-pollinate {}", plant_name(*pollen_planter))
+                }
+                SeedOrigin::Cutting => format!("Take a cutting of {}", plant_name(*pollen_planter)),
+            };
+            format!("{} ({})", action, get_outlook_text(outlook))
+        }
+        Hint::KeepSeed { index, outlook } => format!(
+            "Keep seed {} from the pod ({})",
+            index + 1,
+            get_outlook_text(outlook)
+        ),
+        Hint::DiscardSeedPod => "Discard the seed pod, there's no room for it".to_string(),
+        Hint::PlantSeed {
+            seed,
+            planter,
+            outlook,
+        } => format!(
+            "Plant seed {} in planter {} ({})",
+            seed + 1,
+            planter + 1,
+            get_outlook_text(outlook)
+        ),
+        Hint::NextSeason => "Try moving on to the next season".to_string(),
+    }
+}
+
+/// Describes how promising the provided outlook is, in as few words as possible.
+fn get_outlook_text(outlook: &Outlook) -> String {
+    let win_percent = outlook.win_chance * 100.0;
+    if win_percent >= 1.0 {
+        format!("{:.0}% chance to win", win_percent)
+    } else {
+        format!("about {:.1} intelligence", outlook.expected_intelligence)
+    }
+}
+
+type InteractedCloseSeasonLogButtonTuple = (Changed<Interaction>, With<CloseSeasonLogButton>);
+
+/// Handles interactions with the close season log button
+fn close_season_log_button_system(
+    mut season_log_screen_query: Query<&mut Visibility, With<SeasonLogScreen>>,
+    interaction_query: Query<&Interaction, InteractedCloseSeasonLogButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            for mut visibility in season_log_screen_query.iter_mut() {
+                visibility.is_visible = false;
+            }
+        }
+    }
+}
+
+type InteractedCloseSeasonReportButtonTuple = (Changed<Interaction>, With<CloseSeasonReportButton>);
+
+/// Handles interactions with the button for dismissing the season report
+fn close_season_report_button_system(
+    mut commands: Commands,
+    season_report_query: Query<Entity, With<SeasonReportPanel>>,
+    interaction_query: Query<&Interaction, InteractedCloseSeasonReportButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            despawn_components(season_report_query, &mut commands);
+            return;
+        }
+    }
+}
+
+/// Scrolls whichever lists are visible with the mouse wheel.
+fn scrolling_list_system(
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    mut scrolling_list_query: Query<(
+        &mut ScrollingList,
+        &mut Style,
+        &Children,
+        &Node,
+        &ComputedVisibility,
+    )>,
+    entry_query: Query<&Node>,
+) {
+    for mouse_wheel_event in mouse_wheel_events.iter() {
+        for (mut scrolling_list, mut style, children, node, visibility) in
+            scrolling_list_query.iter_mut()
+        {
+            if !visibility.is_visible() {
+                continue;
+            }
+
+            let entries_height: f32 = children
+                .iter()
+                .filter_map(|entity| entry_query.get(*entity).ok())
+                .map(|entry| entry.size.y)
+                .sum();
+            let max_scroll = (entries_height - node.size.y).max(0.0);
+
+            let scroll_amount = match mouse_wheel_event.unit {
+                MouseScrollUnit::Line => mouse_wheel_event.y * SCROLL_LINE_HEIGHT,
+                MouseScrollUnit::Pixel => mouse_wheel_event.y,
+            };
+
+            scrolling_list.position =
+                (scrolling_list.position + scroll_amount).clamp(-max_scroll, 0.0);
+            style.position.top = Val::Px(scrolling_list.position);
+        }
+    }
+}
+
+/// Shows a summary of each season as it's added to the log, and keeps the season log screen up to date.
+fn season_log_display_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    season_log: Res<SeasonLog>,
+    season_report_query: Query<Entity, With<SeasonReportPanel>>,
+    mut season_log_list_query: Query<(Entity, &mut ScrollingList, &mut Style), With<SeasonLogList>>,
+) {
+    if !season_log.is_changed() {
+        return;
+    }
+
+    let main_font = asset_server.load(MAIN_FONT);
+    let title_font = asset_server.load(TITLE_FONT);
+
+    despawn_components(season_report_query, &mut commands);
+    if let Some(report) = season_log.0.last() {
+        spawn_season_report_panel(&mut commands, report, &main_font, &title_font);
+    }
+
+    for (entity, mut scrolling_list, mut style) in season_log_list_query.iter_mut() {
+        scrolling_list.position = 0.0;
+        style.position.top = Val::Px(0.0);
+
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|parent| {
+                spawn_season_log_entries(parent, &season_log.0, &main_font);
+            });
+    }
+}
+
+/// Shows a dismissible summary of what happened in a season.
+fn spawn_season_report_panel(
+    commands: &mut Commands,
+    report: &SeasonReport,
+    main_font: &Handle<Font>,
+    title_font: &Handle<Font>,
+) {
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(45.0), Val::Undefined),
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    left: Val::Percent(40.0),
+                    top: Val::Px(TOP_BAR_HEIGHT + 10.0),
+                    ..default()
+                },
+                flex_direction: FlexDirection::ColumnReverse,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            color: Color::rgba(0.1, 0.1, 0.1, 0.95).into(),
+            ..default()
+        })
+        .insert(GameComponent)
+        .insert(SeasonReportPanel)
+        .with_children(|parent| {
+            parent.spawn_bundle(TextBundle::from_section(
+                format!("Season {}", report.season),
+                TextStyle {
+                    font: title_font.clone(),
+                    font_size: 30.0,
+                    color: Color::WHITE,
+                },
+            ));
+
+            parent.spawn_bundle(
+                TextBundle::from_section(
+                    get_season_report_text(report),
+                    TextStyle {
+                        font: main_font.clone(),
+                        font_size: 20.0,
+                        color: Color::WHITE,
+                    },
+                )
+                .with_text_alignment(TextAlignment::CENTER)
+                .with_style(Style {
+                    margin: UiRect::all(Val::Px(10.0)),
+                    max_size: Size {
+                        width: Val::Px(WINDOW_WIDTH * 0.4),
+                        ..default()
+                    },
+                    ..default()
+                }),
+            );
+
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    color: Color::NONE.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    spawn_season_report_button(parent, "Log", SeasonLogButton, main_font);
+                    spawn_season_report_button(parent, "OK", CloseSeasonReportButton, main_font);
+                });
+        });
+}
+
+fn spawn_season_report_button(
+    parent: &mut ChildBuilder,
+    text: &str,
+    component: impl Component,
+    font: &Handle<Font>,
+) {
+    parent
+        .spawn_bundle(ButtonBundle {
+            style: Style {
+                size: Size::new(Val::Px(120.0), Val::Px(40.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(5.0)),
+                ..default()
+            },
+            color: NORMAL_BUTTON.into(),
+            ..default()
+        })
+        .insert(component)
+        .with_children(|parent| {
+            parent.spawn_bundle(TextBundle::from_section(
+                text,
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 25.0,
+                    color: Color::SEA_GREEN,
+                },
+            ));
+        });
+}
+
+/// Lists the provided season reports, newest first.
+fn spawn_season_log_entries(
+    parent: &mut ChildBuilder,
+    reports: &[SeasonReport],
+    font: &Handle<Font>,
 ) {
-    season.0 += 1;
+    if reports.is_empty() {
+        parent.spawn_bundle(TextBundle::from_section(
+            "Nothing to report yet. Come back after the next season.",
+            TextStyle {
+                font: font.clone(),
+                font_size: 25.0,
+                color: Color::WHITE,
+            },
+        ));
+    }
 
-    for mut season_text in season_text_query.iter_mut() {
-        season_text.sections[0].value = format!("Season {}", season.0);
+    for report in reports.iter().rev() {
+        parent.spawn_bundle(
+            TextBundle::from_sections([
+                TextSection::new(
+                    format!("Season {}\n", report.season),
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 25.0,
+                        color: Color::SEA_GREEN,
+                    },
+                ),
+                TextSection::new(
+                    get_season_report_text(report),
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 20.0,
+                        color: Color::WHITE,
+                    },
+                ),
+            ])
+            .with_style(Style {
+                flex_shrink: 0.0,
+                margin: UiRect {
+                    bottom: Val::Px(15.0),
+                    ..default()
+                },
+                max_size: Size {
+                    width: Val::Px(WINDOW_WIDTH * 0.8),
+                    ..default()
+                },
+                ..default()
+            }),
+        );
     }
 }
 
-type InteractedRestartButtonTuple = (Changed<Interaction>, With<RestartButton>);
+/// Describes what happened in a season.
+fn get_season_report_text(report: &SeasonReport) -> String {
+    let mut lines = Vec::new();
 
-/// Handles interactions with the restart button.
-fn restart_button_system(
-    mut game_state: ResMut<State<GameState>>,
-    mut set_up: ResMut<SetUp>,
-    interaction_query: Query<&Interaction, InteractedRestartButtonTuple>,
-) {
-    for interaction in interaction_query.iter() {
-        if *interaction == Interaction::Clicked {
-            set_up.0 = false;
-            game_state.set(GameState::GameLoading).unwrap();
-        }
+    if report.grown.is_empty() {
+        lines.push("No seeds sprouted.".to_string());
+    } else {
+        let names = report
+            .grown
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        lines.push(format!("Sprouted: {}", names.join(", ")));
     }
-}
 
-type InteractedHelpButtonTuple = (Changed<Interaction>, With<HelpButton>);
+    match report.failed_seeds {
+        0 => (),
+        1 => lines.push("1 seed failed to sprout.".to_string()),
+        x => lines.push(format!("{x} seeds failed to sprout.")),
+    }
 
-/// Handles interactions with the help button
-fn help_button_system(
-    mut help_screen_query: Query<&mut Visibility, With<HelpScreen>>,
-    interaction_query: Query<&Interaction, InteractedHelpButtonTuple>,
-) {
-    for interaction in interaction_query.iter() {
-        if *interaction == Interaction::Clicked {
-            for mut visibility in help_screen_query.iter_mut() {
-                visibility.is_visible = true;
-            }
+    if report.deaths.is_empty() {
+        lines.push("No plants died.".to_string());
+    } else {
+        let deaths = report
+            .deaths
+            .iter()
+            .map(|(name, cause_of_death)| format!("{name} ({cause_of_death})"))
+            .collect::<Vec<_>>();
+        lines.push(format!("Died: {}", deaths.join(", ")));
+    }
+
+    match &report.stats {
+        Some(stats) => {
+            let plants = match stats.living_plants {
+                1 => "1 plant".to_string(),
+                x => format!("{x} plants"),
+            };
+            lines.push(format!(
+                "{plants} alive, the smartest with {} intelligence.",
+                stats.best_intelligence
+            ));
+            lines.push(format!(
+                "Average intelligence: {:.1}, average pest resistance: {:.1}",
+                stats.average_intelligence, stats.average_pest_resistance
+            ));
         }
+        None => lines.push("No plants are alive.".to_string()),
     }
-}
 
-type InteractedCloseHelpButtonTuple = (Changed<Interaction>, With<CloseHelpButton>);
+    lines.join("\n")
+}
 
-/// Handles interactions with the close help button
-fn close_help_button_system(
-    mut help_screen_query: Query<&mut Visibility, With<HelpScreen>>,
-    interaction_query: Query<&Interaction, InteractedCloseHelpButtonTuple>,
+/// Handles keeping track of which gene interactions have shown up in plants.
+fn discover_gene_interactions_system(
+    planters: Res<Planters>,
+    mut discovered_gene_interactions: ResMut<DiscoveredGeneInteractions>,
+    mut synergies_text_query: Query<&mut Text, With<SynergiesText>>,
 ) {
-    for interaction in interaction_query.iter() {
-        if *interaction == Interaction::Clicked {
-            for mut visibility in help_screen_query.iter_mut() {
-                visibility.is_visible = false;
+    if !planters.is_changed() {
+        return;
+    }
+
+    for planter in planters.0.iter() {
+        if let Planter::Plant(plant) = planter {
+            for interaction in plant.get_phenotype().gene_interactions {
+                if !discovered_gene_interactions.0.contains(&interaction.name) {
+                    discovered_gene_interactions.0.push(interaction.name);
+                }
             }
         }
     }
+
+    for mut text in synergies_text_query.iter_mut() {
+        text.sections[0].value = get_gene_interactions_text(&discovered_gene_interactions.0);
+    }
+}
+
+/// Builds the text listing all the gene interactions, with the undiscovered ones hidden.
+fn get_gene_interactions_text(discovered_names: &[&'static str]) -> String {
+    let interaction_lines = GENE_INTERACTIONS
+        .iter()
+        .map(|interaction| {
+            if discovered_names.contains(&interaction.name) {
+                format!("{}: {}", interaction.name, interaction.description)
+            } else {
+                "???".to_string()
+            }
+        })
+        .collect::<Vec<String>>();
+
+    format!("Discovered synergies\n\n{}", interaction_lines.join("\n"))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn plant_display_system(
     planters: Res<Planters>,
+    sexed_flowers: Res<SexedFlowers>,
+    display_settings: Res<PlantDisplaySettings>,
     commands: Commands,
     image_assets: Res<ImageAssets>,
     plant_spaces_query: Query<(&Transform, &PlantSpace)>,
     plant_images_query: Query<Entity, With<PlantImage>>,
     plant_info_query: Query<(&mut Text, &PlantInfo)>,
 ) {
-    if !planters.is_changed() {
+    if !planters.is_changed() && !display_settings.is_changed() {
         return;
     }
 
     update_plant_display(
         planters,
+        sexed_flowers,
+        display_settings,
         commands,
         image_assets,
         plant_spaces_query,
@@ -861,8 +3276,11 @@ fn plant_display_system(
     );
 }
 
+#[allow(clippy::too_many_arguments)]
 fn update_plant_display(
     planters: Res<Planters>,
+    sexed_flowers: Res<SexedFlowers>,
+    display_settings: Res<PlantDisplaySettings>,
     mut commands: Commands,
     image_assets: Res<ImageAssets>,
     plant_spaces_query: Query<(&Transform, &PlantSpace)>,
@@ -888,6 +3306,7 @@ fn update_plant_display(
                         &mut commands,
                         transform,
                         &phenotype,
+                        &display_settings,
                         &image_assets,
                         plant_space.0,
                         GameComponent,
@@ -898,6 +3317,14 @@ fn update_plant_display(
                         let phenotype = plant.get_phenotype();
 
                         let name_text = format!("Name: {}", plant.name);
+                        let mut plant_traits = vec![phenotype.lifespan.to_string()];
+                        if sexed_flowers.0 {
+                            plant_traits.push(phenotype.flower_type.to_string());
+                        }
+                        if plant.is_sterile() {
+                            plant_traits.push("sterile".to_string());
+                        }
+                        let age_text = format!("Age: {} ({})", plant.age, plant_traits.join(", "));
 
                         let intelligence = if phenotype.intelligence < 0 {
                             0
@@ -923,11 +3350,12 @@ fn update_plant_display(
                     "Pest Resistance:\n[{pest_resistance_filled_bar}{pest_resistance_empty_bar}]"
                 );
 
-                        text.sections[0].value =
-                            format!("{name_text}\n\n{intelligence_text}\n{pest_resistance_text}");
+                        text.sections[0].value = format!(
+                            "{name_text}\n{age_text}\n{intelligence_text}\n{pest_resistance_text}"
+                        );
                     }
                 }
-                Planter::DeadPlant(dead_plant) => {
+                Planter::DeadPlant(dead_plant, cause_of_death) => {
                     // spawn plant image
                     commands
                         .spawn_bundle(SpriteBundle {
@@ -961,8 +3389,12 @@ fn update_plant_display(
 
                     // update plant info
                     if let Some(text) = plant_info_text_map.get_mut(&plant_space.0) {
+                        let cause_text = match cause_of_death {
+                            CauseOfDeath::Pests => "Eaten by pests",
+                            CauseOfDeath::OldAge => "Died of old age",
+                        };
                         text.sections[0].value =
-                            format!("RIP {}\n\nEaten by pests", dead_plant.name);
+                            format!("RIP {}\n\n{}", dead_plant.name, cause_text);
                     }
                 }
                 Planter::Seed(seed) => {
@@ -999,10 +3431,49 @@ fn update_plant_display(
 
                     // update plant info
                     if let Some(text) = plant_info_text_map.get_mut(&plant_space.0) {
-                        text.sections[0].value = format!(
-                            "A seed made from\n{}\nand\n{}",
-                            seed.parent_name_1, seed.parent_name_2
-                        );
+                        text.sections[0].value = get_planted_seed_text(seed);
+                    }
+                }
+                Planter::FailedSeed(seed) => {
+                    // spawn plant image
+                    commands
+                        .spawn_bundle(SpriteBundle {
+                            sprite: Sprite {
+                                custom_size: Some(Vec2::new(PLANT_SPACE_SIZE, PLANT_SPACE_SIZE)),
+                                color: Color::NONE,
+                                ..default()
+                            },
+                            transform: Transform {
+                                translation: Vec3::new(
+                                    transform.translation.x,
+                                    transform.translation.y
+                                        + ((PLANT_SPACE_HEIGHT - PLANT_SPACE_SIZE) / 2.0),
+                                    PLANTS_LAYER,
+                                ),
+                                ..default()
+                            },
+                            ..default()
+                        })
+                        .insert(GameComponent)
+                        .insert(PlantImage(plant_space.0))
+                        .insert(Interactable {
+                            size: Vec2::new(200.0, 200.0),
+                        })
+                        .with_children(|parent| {
+                            parent.spawn_bundle(SpriteBundle {
+                                texture: image_assets.planted_seed.clone(),
+                                sprite: Sprite {
+                                    color: FAILED_SEED_COLOR,
+                                    ..default()
+                                },
+                                ..default()
+                            });
+                        });
+
+                    // update plant info
+                    if let Some(text) = plant_info_text_map.get_mut(&plant_space.0) {
+                        text.sections[0].value =
+                            format!("{}\n\nFailed to sprout", get_planted_seed_text(seed));
                     }
                 }
                 Planter::Empty => (),
@@ -1011,10 +3482,27 @@ fn update_plant_display(
     }
 }
 
+fn get_planted_seed_text(seed: &Seed) -> String {
+    match seed.origin {
+        SeedOrigin::Spliced => format!(
+            "A seed made from\n{}\nand\n{}",
+            seed.parent_name_1, seed.parent_name_2
+        ),
+        SeedOrigin::SelfPollinated => {
+            format!("A seed made from\n{}\nand itself", seed.parent_name_1)
+        }
+        SeedOrigin::Cutting => format!(
+            "A cutting from\n{}\n\nSeasons left: {}",
+            seed.parent_name_1, seed.seasons_to_grow
+        ),
+    }
+}
+
 pub fn spawn_plant_image(
     commands: &mut Commands,
     plant_space_transform: &Transform,
     phenotype: &Phenotype,
+    display_settings: &PlantDisplaySettings,
     image_assets: &Res<ImageAssets>,
     plant_id: usize,
     component: impl Component,
@@ -1048,7 +3536,10 @@ pub fn spawn_plant_image(
             parent.spawn_bundle(SpriteBundle {
                 texture: get_image_for_stem_style(&phenotype.stem_style, image_assets),
                 sprite: Sprite {
-                    color: get_color_for_stem_color(&phenotype.stem_color),
+                    color: get_color_for_stem_color(
+                        &phenotype.stem_color,
+                        display_settings.palette,
+                    ),
                     ..default()
                 },
                 ..default()
@@ -1058,7 +3549,10 @@ pub fn spawn_plant_image(
             parent.spawn_bundle(SpriteBundle {
                 texture: get_image_for_fruit_style(&phenotype.fruit_style, image_assets),
                 sprite: Sprite {
-                    color: get_color_for_fruit_color(&phenotype.fruit_color),
+                    color: get_color_for_fruit_color(
+                        &phenotype.fruit_color,
+                        display_settings.palette,
+                    ),
                     ..default()
                 },
                 transform: Transform {
@@ -1067,6 +3561,24 @@ pub fn spawn_plant_image(
                 },
                 ..default()
             });
+
+            // fruit pattern
+            if display_settings.fruit_patterns {
+                if let Some(pattern) = get_pattern_for_fruit(
+                    &phenotype.fruit_style,
+                    &phenotype.fruit_color,
+                    image_assets,
+                ) {
+                    parent.spawn_bundle(SpriteBundle {
+                        texture: pattern,
+                        transform: Transform {
+                            translation: Vec3::new(0.0, 0.0, 1.5),
+                            ..default()
+                        },
+                        ..default()
+                    });
+                }
+            }
         });
 }
 
@@ -1087,19 +3599,73 @@ fn get_image_for_fruit_style(style: &FruitStyle, image_assets: &Res<ImageAssets>
     }
 }
 
-fn get_color_for_stem_color(color: &StemColor) -> Color {
-    match color {
-        StemColor::Brown => Color::rgb(0.32, 0.27, 0.14),
-        StemColor::Green => Color::DARK_GREEN,
-        StemColor::Blue => Color::rgb(0.09, 0.37, 0.64),
+/// Gets the image to draw over fruit to show its colour with a pattern, if it has one.
+/// Red fruit is left plain, purple fruit is striped, and yellow fruit is dotted.
+fn get_pattern_for_fruit(
+    style: &FruitStyle,
+    color: &FruitColor,
+    image_assets: &Res<ImageAssets>,
+) -> Option<Handle<Image>> {
+    match (color, style) {
+        (FruitColor::Red, _) => None,
+        (FruitColor::Purple, FruitStyle::Circle) => Some(image_assets.fruit_circle_stripes.clone()),
+        (FruitColor::Purple, FruitStyle::Square) => Some(image_assets.fruit_square_stripes.clone()),
+        (FruitColor::Purple, FruitStyle::Triangle) => {
+            Some(image_assets.fruit_triangle_stripes.clone())
+        }
+        (FruitColor::Yellow, FruitStyle::Circle) => Some(image_assets.fruit_circle_dots.clone()),
+        (FruitColor::Yellow, FruitStyle::Square) => Some(image_assets.fruit_square_dots.clone()),
+        (FruitColor::Yellow, FruitStyle::Triangle) => {
+            Some(image_assets.fruit_triangle_dots.clone())
+        }
+    }
+}
+
+fn get_color_for_stem_color(color: &StemColor, palette: ColorPalette) -> Color {
+    match palette {
+        ColorPalette::Standard => match color {
+            StemColor::Brown => Color::rgb(0.32, 0.27, 0.14),
+            StemColor::Green => Color::DARK_GREEN,
+            StemColor::Blue => Color::rgb(0.09, 0.37, 0.64),
+        },
+        // red-green colour blindness, so green and brown differ in blueness and brightness instead
+        ColorPalette::Deuteranopia | ColorPalette::Protanopia => match color {
+            StemColor::Brown => Color::rgb(0.6, 0.4, 0.0),
+            StemColor::Green => Color::rgb(0.0, 0.62, 0.45),
+            StemColor::Blue => Color::rgb(0.0, 0.3, 0.55),
+        },
+        // blue-yellow colour blindness, so green and blue differ in brightness instead
+        ColorPalette::Tritanopia => match color {
+            StemColor::Brown => Color::rgb(0.6, 0.25, 0.2),
+            StemColor::Green => Color::rgb(0.3, 0.75, 0.7),
+            StemColor::Blue => Color::rgb(0.15, 0.15, 0.35),
+        },
     }
 }
 
-fn get_color_for_fruit_color(color: &FruitColor) -> Color {
-    match color {
-        FruitColor::Red => Color::RED,
-        FruitColor::Purple => Color::PURPLE,
-        FruitColor::Yellow => Color::YELLOW,
+fn get_color_for_fruit_color(color: &FruitColor, palette: ColorPalette) -> Color {
+    match palette {
+        ColorPalette::Standard => match color {
+            FruitColor::Red => Color::RED,
+            FruitColor::Purple => Color::PURPLE,
+            FruitColor::Yellow => Color::YELLOW,
+        },
+        ColorPalette::Deuteranopia => match color {
+            FruitColor::Red => Color::rgb(0.84, 0.37, 0.0),
+            FruitColor::Purple => Color::rgb(0.8, 0.47, 0.65),
+            FruitColor::Yellow => Color::rgb(0.94, 0.89, 0.26),
+        },
+        // reds look darker with protanopia, so purple is pushed towards blue to stay distinct
+        ColorPalette::Protanopia => match color {
+            FruitColor::Red => Color::rgb(0.9, 0.45, 0.0),
+            FruitColor::Purple => Color::rgb(0.35, 0.2, 0.8),
+            FruitColor::Yellow => Color::rgb(0.94, 0.89, 0.26),
+        },
+        ColorPalette::Tritanopia => match color {
+            FruitColor::Red => Color::rgb(0.85, 0.1, 0.2),
+            FruitColor::Purple => Color::rgb(0.4, 0.1, 0.5),
+            FruitColor::Yellow => Color::rgb(1.0, 0.9, 0.75),
+        },
     }
 }
 
@@ -1184,29 +3750,279 @@ fn update_seed_display(
                         ),
                         ..default()
                     },
-                    visibility: Visibility { is_visible: false },
+                    visibility: Visibility { is_visible: false },
+                    ..default()
+                })
+                .insert(GameComponent)
+                .insert(SeedInfo(seed_space.0))
+                .with_children(|parent| {
+                    parent.spawn_bundle(Text2dBundle {
+                        text: Text::from_section(
+                            get_seed_tooltip_text(seed),
+                            TextStyle {
+                                font: main_font.clone(),
+                                font_size: 20.0,
+                                color: Color::WHITE,
+                            },
+                        )
+                        .with_alignment(TextAlignment::CENTER),
+                        transform: Transform {
+                            translation: Vec3::new(0.0, 0.0, 1.0),
+                            ..default()
+                        },
+                        ..default()
+                    });
+                });
+        }
+    }
+}
+
+fn get_seed_tooltip_text(seed: &Seed) -> String {
+    match seed.origin {
+        SeedOrigin::Spliced => format!("{}\n+\n{}", seed.parent_name_1, seed.parent_name_2),
+        SeedOrigin::SelfPollinated => format!("{}\n+\nitself", seed.parent_name_1),
+        SeedOrigin::Cutting => format!("Cutting from\n{}", seed.parent_name_1),
+    }
+}
+
+/// Handles updating the genetic diversity stats in the top bar.
+fn diversity_display_system(
+    planters: Res<Planters>,
+    seeds: Res<Seeds>,
+    mut diversity_text_query: Query<&mut Text, With<DiversityText>>,
+) {
+    if !planters.is_changed() && !seeds.is_changed() {
+        return;
+    }
+
+    let plant_heterozygosities = planters
+        .0
+        .iter()
+        .filter_map(|planter| match planter {
+            Planter::Plant(plant) => Some(get_heterozygosity(&plant.genes)),
+            _ => None,
+        })
+        .collect::<Vec<f32>>();
+    let average_heterozygosity = if plant_heterozygosities.is_empty() {
+        0.0
+    } else {
+        plant_heterozygosities.iter().sum::<f32>() / plant_heterozygosities.len() as f32
+    };
+
+    let diversity = get_genetic_diversity(&planters, &seeds);
+
+    for mut text in diversity_text_query.iter_mut() {
+        text.sections[0].value = format!(
+            "Heterozygosity: {:.0}%   Diversity: {:.0}%",
+            average_heterozygosity * 100.0,
+            diversity * 100.0
+        );
+    }
+}
+
+/// Handles showing the seeds in the seed pod for the player to pick from.
+fn seed_pod_display_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    seed_pod: Res<SeedPod>,
+    seed_pod_query: Query<Entity, With<SeedPodComponent>>,
+) {
+    if !seed_pod.is_changed() {
+        return;
+    }
+
+    despawn_components(seed_pod_query, &mut commands);
+
+    if seed_pod.0.is_empty() {
+        return;
+    }
+
+    let main_font = asset_server.load(MAIN_FONT);
+
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(60.0), Val::Percent(60.0)),
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    left: Val::Percent(20.0),
+                    top: Val::Percent(20.0),
+                    ..default()
+                },
+                justify_content: JustifyContent::SpaceEvenly,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            color: Color::rgba(0.1, 0.1, 0.1, 0.99).into(),
+            ..default()
+        })
+        .insert(GameComponent)
+        .insert(SeedPodComponent)
+        .with_children(|parent| {
+            // header text
+            parent.spawn_bundle(
+                TextBundle::from_section(
+                    "Pick which seeds to keep",
+                    TextStyle {
+                        font: main_font.clone(),
+                        font_size: 30.0,
+                        color: Color::WHITE,
+                    },
+                )
+                .with_style(Style {
+                    position_type: PositionType::Absolute,
+                    position: UiRect {
+                        top: Val::Px(10.0),
+                        ..default()
+                    },
+                    ..default()
+                }),
+            );
+
+            for (i, seed) in seed_pod.0.iter().enumerate() {
+                let phenotype = seed.get_phenotype();
+                parent
+                    .spawn_bundle(NodeBundle {
+                        style: Style {
+                            size: Size::new(Val::Px(200.0), Val::Percent(60.0)),
+                            flex_direction: FlexDirection::ColumnReverse,
+                            justify_content: JustifyContent::SpaceAround,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        color: UiColor(Color::NONE),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        // seed info
+                        parent.spawn_bundle(
+                            TextBundle::from_section(
+                                format!(
+                                    "Seed {}\n\nIntelligence: {}\nPest Resistance: {}",
+                                    i + 1,
+                                    phenotype.intelligence,
+                                    phenotype.pest_resistance
+                                ),
+                                TextStyle {
+                                    font: main_font.clone(),
+                                    font_size: 25.0,
+                                    color: Color::WHITE,
+                                },
+                            )
+                            .with_text_alignment(TextAlignment::CENTER),
+                        );
+
+                        // keep button
+                        parent
+                            .spawn_bundle(ButtonBundle {
+                                style: Style {
+                                    size: Size::new(Val::Px(150.0), Val::Px(40.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                color: NORMAL_BUTTON.into(),
+                                ..default()
+                            })
+                            .insert(KeepSeedButton(i))
+                            .with_children(|parent| {
+                                parent.spawn_bundle(TextBundle::from_section(
+                                    "Keep",
+                                    TextStyle {
+                                        font: main_font.clone(),
+                                        font_size: 30.0,
+                                        color: Color::SEA_GREEN,
+                                    },
+                                ));
+                            });
+                    });
+            }
+
+            // discard button
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(250.0), Val::Px(40.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        position_type: PositionType::Absolute,
+                        position: UiRect {
+                            bottom: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    color: NORMAL_BUTTON.into(),
                     ..default()
                 })
-                .insert(GameComponent)
-                .insert(SeedInfo(seed_space.0))
+                .insert(DiscardSeedPodButton)
                 .with_children(|parent| {
-                    parent.spawn_bundle(Text2dBundle {
-                        text: Text::from_section(
-                            format!("{}\n+\n{}", seed.parent_name_1, seed.parent_name_2),
-                            TextStyle {
-                                font: main_font.clone(),
-                                font_size: 20.0,
-                                color: Color::WHITE,
-                            },
-                        )
-                        .with_alignment(TextAlignment::CENTER),
-                        transform: Transform {
-                            translation: Vec3::new(0.0, 0.0, 1.0),
-                            ..default()
+                    parent.spawn_bundle(TextBundle::from_section(
+                        "Discard the rest",
+                        TextStyle {
+                            font: main_font.clone(),
+                            font_size: 30.0,
+                            color: Color::SEA_GREEN,
                         },
-                        ..default()
-                    });
+                    ));
                 });
+        });
+}
+
+type InteractedKeepSeedButtonTuple = (Changed<Interaction>, With<KeepSeedButton>);
+
+/// Handles interactions with the buttons for keeping seeds from the seed pod.
+fn keep_seed_button_system(
+    mut seeds: ResMut<Seeds>,
+    mut seed_pod: ResMut<SeedPod>,
+    mut replay: ResMut<Replay>,
+    mut status_text_query: Query<&mut Text, StatusTextFilter>,
+    interaction_query: Query<(&Interaction, &KeepSeedButton), InteractedKeepSeedButtonTuple>,
+) {
+    for (interaction, keep_seed_button) in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            keep_seed_from_pod(
+                keep_seed_button.0,
+                &mut seeds,
+                &mut seed_pod,
+                &mut status_text_query,
+            );
+            replay
+                .actions
+                .push(ReplayAction::KeepSeed(keep_seed_button.0));
+        }
+    }
+}
+
+/// Moves the seed with the provided index from the seed pod to the player's seeds, if there's room for it.
+fn keep_seed_from_pod(
+    index: usize,
+    seeds: &mut Seeds,
+    seed_pod: &mut SeedPod,
+    status_text_query: &mut Query<&mut Text, StatusTextFilter>,
+) {
+    if seeds.0.len() >= NUM_SEED_SPACES {
+        show_status_message(
+            status_text_query,
+            "There's no room for any more seeds!".to_string(),
+        );
+    } else if index < seed_pod.0.len() {
+        seeds.0.push(seed_pod.0.remove(index));
+    }
+}
+
+type InteractedDiscardSeedPodButtonTuple = (Changed<Interaction>, With<DiscardSeedPodButton>);
+
+/// Handles interactions with the button for discarding the rest of the seed pod.
+fn discard_seed_pod_button_system(
+    mut seed_pod: ResMut<SeedPod>,
+    mut replay: ResMut<Replay>,
+    interaction_query: Query<&Interaction, InteractedDiscardSeedPodButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            seed_pod.0.clear();
+            replay.actions.push(ReplayAction::DiscardSeedPod);
         }
     }
 }
@@ -1269,17 +4085,25 @@ fn draggable_pickup_system(
     buttons: Res<Input<MouseButton>>,
     cursor_position: Res<CursorPosition>,
     mut commands: Commands,
+    sound_effects: SoundEffects,
     draggable_query: Query<(&Transform, &Interactable, Entity), With<Draggable>>,
 ) {
     if buttons.just_pressed(MouseButton::Left) {
         if let Some(pos) = cursor_position.0 {
+            let mut picked_up = false;
             for (transform, interactable, entity) in draggable_query.iter() {
                 if intersects(pos, transform.translation.truncate(), interactable.size) {
                     commands.entity(entity).insert(BeingDragged {
                         original_position: transform.translation,
+                        drop_handled: false,
                     });
+                    picked_up = true;
                 }
             }
+
+            if picked_up {
+                sound_effects.play(SoundEffect::PickUp);
+            }
         }
     }
 }
@@ -1292,17 +4116,28 @@ fn intersects(point: Vec2, center_point: Vec2, size: Vec2) -> bool {
         && point.y <= center_point.y + (size.y / 2.0) + 1.0
 }
 
+#[allow(clippy::too_many_arguments)]
 fn plant_splice_system(
     mouse_buttons: Res<Input<MouseButton>>,
+    keyboard: Res<Input<KeyCode>>,
     cursor_position: Res<CursorPosition>,
     planters: Res<Planters>,
+    sexed_flowers: Res<SexedFlowers>,
     mut seeds: ResMut<Seeds>,
-    dragged_plant_query: Query<&PlantImage, With<BeingDragged>>,
+    mut seed_pod: ResMut<SeedPod>,
+    mut status_text_query: Query<&mut Text, StatusTextFilter>,
+    mut replay: ResMut<Replay>,
+    sound_effects: SoundEffects,
+    mut seed_created_events: EventWriter<SeedCreated>,
+    mut dragged_plant_query: Query<(&PlantImage, &mut BeingDragged)>,
     plant_space_query: Query<(&Transform, &PlantSpace, &Interactable)>,
 ) {
     if !mouse_buttons.pressed(MouseButton::Left) {
         if let Some(pos) = cursor_position.0 {
-            let dragged_plant_id = dragged_plant_query.get_single().ok().map(|image| image.0);
+            let dragged_plant_id = dragged_plant_query
+                .get_single()
+                .ok()
+                .map(|(image, _)| image.0);
             let mut target_plant_id = None;
             for (transform, plant_space, interactable) in plant_space_query.iter() {
                 if intersects(pos, transform.translation.truncate(), interactable.size) {
@@ -1311,18 +4146,55 @@ fn plant_splice_system(
                 }
             }
 
-            if dragged_plant_id == target_plant_id {
-                return;
-            }
-
             let dragged_plant = dragged_plant_id.and_then(|id| planters.with_id(id));
             let target_plant = target_plant_id.and_then(|id| planters.with_id(id));
 
-            if let Some(Planter::Plant(plant_1)) = dragged_plant {
-                if let Some(Planter::Plant(plant_2)) = target_plant {
-                    if seeds.0.len() < NUM_SEED_SPACES {
-                        let new_seed = splice_plants(plant_1, plant_2);
-                        seeds.0.push(new_seed);
+            if let (Some(pollen_planter), Some(Planter::Plant(plant_1))) =
+                (dragged_plant_id, dragged_plant)
+            {
+                if let (Some(pistil_planter), Some(Planter::Plant(plant_2))) =
+                    (target_plant_id, target_plant)
+                {
+                    let origin = if keyboard.pressed(KeyCode::LAlt)
+                        || keyboard.pressed(KeyCode::RAlt)
+                    {
+                        // swapping plants around rather than splicing them
+                        return;
+                    } else if dragged_plant_id != target_plant_id {
+                        SeedOrigin::Spliced
+                    } else if keyboard.pressed(KeyCode::LShift) || keyboard.pressed(KeyCode::RShift)
+                    {
+                        SeedOrigin::SelfPollinated
+                    } else if keyboard.pressed(KeyCode::LControl)
+                        || keyboard.pressed(KeyCode::RControl)
+                    {
+                        SeedOrigin::Cutting
+                    } else {
+                        // just dropping a plant back where it was
+                        return;
+                    };
+
+                    let made_seeds = make_seeds(
+                        plant_1,
+                        plant_2,
+                        origin,
+                        &sexed_flowers,
+                        &mut seeds,
+                        &mut seed_pod,
+                        &mut status_text_query,
+                        &mut seed_created_events,
+                    );
+                    play_splice_sound(made_seeds, &sound_effects);
+                    for (_, mut being_dragged) in dragged_plant_query.iter_mut() {
+                        being_dragged.drop_handled = true;
+                    }
+
+                    if made_seeds {
+                        replay.actions.push(ReplayAction::MakeSeeds {
+                            pollen_planter,
+                            pistil_planter,
+                            origin,
+                        });
                     }
                 }
             }
@@ -1330,13 +4202,100 @@ fn plant_splice_system(
     }
 }
 
+/// Makes seeds from the provided plants, or explains in the status bar why it can't.
+/// The first plant provides the pollen, and the second plant receives it.
+/// Returns whether any seeds were made.
+fn make_seeds(
+    plant_1: &Plant,
+    plant_2: &Plant,
+    origin: SeedOrigin,
+    sexed_flowers: &SexedFlowers,
+    seeds: &mut Seeds,
+    seed_pod: &mut SeedPod,
+    status_text_query: &mut Query<&mut Text, StatusTextFilter>,
+    seed_created_events: &mut EventWriter<SeedCreated>,
+) -> bool {
+    if !seed_pod.0.is_empty() {
+        show_status_message(
+            status_text_query,
+            "Deal with the seed pod first!".to_string(),
+        );
+        return false;
+    }
+
+    if seeds.0.len() >= NUM_SEED_SPACES {
+        show_status_message(
+            status_text_query,
+            "There's no room for any more seeds!".to_string(),
+        );
+        return false;
+    }
+
+    if sexed_flowers.0 && origin != SeedOrigin::Cutting && !plant_1.can_pollinate(plant_2) {
+        show_status_message(
+            status_text_query,
+            format!("{} can't pollinate {}!", plant_1.name, plant_2.name),
+        );
+        return false;
+    }
+
+    if origin != SeedOrigin::Cutting {
+        if let Some(sterile_plant) = [plant_1, plant_2]
+            .into_iter()
+            .find(|plant| plant.is_sterile())
+        {
+            show_status_message(
+                status_text_query,
+                format!("{} is sterile and can't be spliced!", sterile_plant.name),
+            );
+            return false;
+        }
+    }
+
+    match origin {
+        SeedOrigin::Spliced => {
+            seed_pod.0 = (0..SEEDS_PER_POD)
+                .map(|_| splice_plants(plant_1, plant_2))
+                .collect();
+        }
+        SeedOrigin::SelfPollinated => {
+            seed_pod.0 = (0..SEEDS_PER_POD)
+                .map(|_| self_pollinate(plant_1))
+                .collect();
+        }
+        SeedOrigin::Cutting => seeds.0.push(take_cutting(plant_1)),
+    }
+
+    seed_created_events.send(SeedCreated {
+        origin,
+        count: match origin {
+            SeedOrigin::Cutting => 1,
+            _ => seed_pod.0.len(),
+        },
+    });
+
+    true
+}
+
+fn play_splice_sound(made_seeds: bool, sound_effects: &SoundEffects) {
+    if made_seeds {
+        sound_effects.play(SoundEffect::Splice);
+    } else {
+        sound_effects.play(SoundEffect::SpliceRejected);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn seed_plant_system(
     mut commands: Commands,
     mouse_buttons: Res<Input<MouseButton>>,
     cursor_position: Res<CursorPosition>,
     mut planters: ResMut<Planters>,
     mut seeds: ResMut<Seeds>,
-    dragged_seed_query: Query<(Entity, &SeedImage), With<BeingDragged>>,
+    mut replay: ResMut<Replay>,
+    sound_effects: SoundEffects,
+    mut seed_planted_events: EventWriter<SeedPlanted>,
+    mut dragged_seed_query: Query<(Entity, &SeedImage, &mut BeingDragged)>,
     plant_space_query: Query<(&Transform, &PlantSpace, &Interactable)>,
 ) {
     if !mouse_buttons.pressed(MouseButton::Left) {
@@ -1350,10 +4309,61 @@ fn seed_plant_system(
             }
 
             if let Some(planter_id) = target_planter_id {
-                for (entity, seed_image) in dragged_seed_query.iter() {
+                for (entity, seed_image, mut being_dragged) in dragged_seed_query.iter_mut() {
                     if let Some(seed) = seeds.take_with_id(seed_image.0) {
                         planters.0[planter_id] = Planter::Seed(seed);
                         commands.entity(entity).despawn_recursive();
+                        sound_effects.play(SoundEffect::Plant);
+                        being_dragged.drop_handled = true;
+                        seed_planted_events.send(SeedPlanted { planter_id });
+                        replay.actions.push(ReplayAction::PlantSeed {
+                            seed: seed_image.0,
+                            planter: planter_id,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Handles moving plants to different planters.
+fn plant_move_system(
+    mut commands: Commands,
+    mouse_buttons: Res<Input<MouseButton>>,
+    keyboard: Res<Input<KeyCode>>,
+    cursor_position: Res<CursorPosition>,
+    mut planters: ResMut<Planters>,
+    mut replay: ResMut<Replay>,
+    dragged_plant_query: Query<(Entity, &PlantImage), With<BeingDragged>>,
+    plant_space_query: Query<(&Transform, &PlantSpace, &Interactable)>,
+) {
+    if !mouse_buttons.pressed(MouseButton::Left) {
+        if let Some(pos) = cursor_position.0 {
+            let mut target_planter_id = None;
+            for (transform, plant_space, interactable) in plant_space_query.iter() {
+                if intersects(pos, transform.translation.truncate(), interactable.size) {
+                    target_planter_id = Some(plant_space.0);
+                    break;
+                }
+            }
+
+            if let Some(target_id) = target_planter_id {
+                for (entity, plant_image) in dragged_plant_query.iter() {
+                    let source_id = plant_image.0;
+                    if source_id == target_id {
+                        continue;
+                    }
+
+                    let swap_plants =
+                        keyboard.pressed(KeyCode::LAlt) || keyboard.pressed(KeyCode::RAlt);
+                    if move_plant(&mut planters, source_id, target_id, swap_plants) {
+                        commands.entity(entity).despawn_recursive();
+                        replay.actions.push(ReplayAction::MovePlant {
+                            from: source_id,
+                            to: target_id,
+                            swap: swap_plants,
+                        });
                     }
                 }
             }
@@ -1361,43 +4371,488 @@ fn seed_plant_system(
     }
 }
 
+/// Moves the plant in the source planter to the target planter, returning whether it was moved.
+fn move_plant(
+    planters: &mut Planters,
+    source_id: usize,
+    target_id: usize,
+    swap_plants: bool,
+) -> bool {
+    // moving a plant onto another plant splices them, unless the player wants to swap them
+    let can_move = match (planters.with_id(source_id), planters.with_id(target_id)) {
+        (Some(Planter::Plant(_)), Some(Planter::Plant(_))) => swap_plants,
+        (Some(Planter::Plant(_)), Some(_)) => true,
+        _ => false,
+    };
+
+    if can_move {
+        planters.0.swap(source_id, target_id);
+    }
+
+    can_move
+}
+
+type SeasonLogScreenFilter = (With<SeasonLogScreen>, Without<HelpScreen>);
+
+/// Handles keyboard and gamepad shortcuts for the buttons on the game screen.
+#[allow(clippy::too_many_arguments)]
+fn keyboard_shortcuts_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    mut paused: ResMut<Paused>,
+    keyboard: Res<Input<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    mut season_transition: ResMut<SeasonTransition>,
+    mut keyboard_focus: ResMut<KeyboardFocus>,
+    mut set_up: ResMut<SetUp>,
+    mut game_state: ResMut<State<GameState>>,
+    mut help_screen_query: Query<&mut Visibility, With<HelpScreen>>,
+    mut season_log_screen_query: Query<&mut Visibility, SeasonLogScreenFilter>,
+) {
+    if keyboard.just_pressed(KeyCode::R) {
+        request_action(
+            ConfirmableAction::Restart,
+            &mut commands,
+            &asset_server,
+            &settings,
+            &mut paused,
+            &mut set_up,
+            &mut game_state,
+        );
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::H)
+        || gamepad_just_pressed(&gamepads, &gamepad_buttons, GamepadButtonType::Select)
+    {
+        for mut visibility in help_screen_query.iter_mut() {
+            visibility.is_visible = !visibility.is_visible;
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::L) {
+        for mut visibility in season_log_screen_query.iter_mut() {
+            visibility.is_visible = !visibility.is_visible;
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::N)
+        || gamepad_just_pressed(&gamepads, &gamepad_buttons, GamepadButtonType::Start)
+    {
+        // whatever was selected might not be there after the season changes
+        keyboard_focus.selected = None;
+        season_transition.start_or_skip();
+    }
+}
+
+/// Handles moving the keyboard and gamepad focus around, and acting on the focused spaces.
+#[allow(clippy::too_many_arguments)]
+fn keyboard_focus_system(
+    keyboard: Res<Input<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    mut keyboard_focus: ResMut<KeyboardFocus>,
+    mut planters: ResMut<Planters>,
+    mut seeds: ResMut<Seeds>,
+    mut seed_pod: ResMut<SeedPod>,
+    sexed_flowers: Res<SexedFlowers>,
+    mut status_text_query: Query<&mut Text, StatusTextFilter>,
+    mut replay: ResMut<Replay>,
+    sound_effects: SoundEffects,
+    mut seed_created_events: EventWriter<SeedCreated>,
+    mut seed_planted_events: EventWriter<SeedPlanted>,
+) {
+    let gamepad_pressed = |button_type: GamepadButtonType| {
+        gamepad_just_pressed(&gamepads, &gamepad_buttons, button_type)
+    };
+
+    let direction = if keyboard.just_pressed(KeyCode::Up)
+        || gamepad_pressed(GamepadButtonType::DPadUp)
+    {
+        Some(FocusDirection::Up)
+    } else if keyboard.just_pressed(KeyCode::Down) || gamepad_pressed(GamepadButtonType::DPadDown) {
+        Some(FocusDirection::Down)
+    } else if keyboard.just_pressed(KeyCode::Left) || gamepad_pressed(GamepadButtonType::DPadLeft) {
+        Some(FocusDirection::Left)
+    } else if keyboard.just_pressed(KeyCode::Right) || gamepad_pressed(GamepadButtonType::DPadRight)
+    {
+        Some(FocusDirection::Right)
+    } else {
+        None
+    };
+
+    let confirm = keyboard.just_pressed(KeyCode::Return)
+        || keyboard.just_pressed(KeyCode::Space)
+        || gamepad_pressed(GamepadButtonType::South);
+    let cancel = keyboard.just_pressed(KeyCode::Back)
+        || keyboard.just_pressed(KeyCode::Escape)
+        || gamepad_pressed(GamepadButtonType::East);
+
+    // the seed pod has to be dealt with before anything else can be done
+    if !seed_pod.0.is_empty() {
+        let pod_index = match keyboard_focus.focused {
+            Some(FocusTarget::PodSeed(index)) => index.min(seed_pod.0.len() - 1),
+            Some(_) => 0,
+            None if direction.is_some() => 0,
+            None => return,
+        };
+        keyboard_focus.selected = None;
+        keyboard_focus.focused = Some(match direction {
+            Some(FocusDirection::Up | FocusDirection::Left) => {
+                FocusTarget::PodSeed(pod_index.saturating_sub(1))
+            }
+            Some(FocusDirection::Down | FocusDirection::Right) => {
+                FocusTarget::PodSeed((pod_index + 1).min(seed_pod.0.len() - 1))
+            }
+            None => FocusTarget::PodSeed(pod_index),
+        });
+
+        if confirm {
+            keep_seed_from_pod(pod_index, &mut seeds, &mut seed_pod, &mut status_text_query);
+            replay.actions.push(ReplayAction::KeepSeed(pod_index));
+        } else if cancel {
+            seed_pod.0.clear();
+            replay.actions.push(ReplayAction::DiscardSeedPod);
+        }
+
+        if seed_pod.0.is_empty() {
+            keyboard_focus.focused = Some(FocusTarget::Seed(seeds.0.len().saturating_sub(1)));
+        }
+        return;
+    }
+
+    if let Some(direction) = direction {
+        keyboard_focus.focused = Some(match keyboard_focus.focused {
+            Some(focused) => move_focus(focused, direction),
+            None => FocusTarget::Planter(0),
+        });
+    }
+
+    if cancel {
+        keyboard_focus.selected = None;
+    }
+
+    let focused = match keyboard_focus.focused {
+        Some(focused) => focused,
+        None => return,
+    };
+
+    // self-pollinating and taking cuttings only need one plant, so they act on the focused plant right away
+    let single_plant_origin =
+        if keyboard.just_pressed(KeyCode::P) || gamepad_pressed(GamepadButtonType::North) {
+            Some(SeedOrigin::SelfPollinated)
+        } else if keyboard.just_pressed(KeyCode::C) || gamepad_pressed(GamepadButtonType::West) {
+            Some(SeedOrigin::Cutting)
+        } else {
+            None
+        };
+
+    if let (Some(origin), FocusTarget::Planter(planter_id)) = (single_plant_origin, focused) {
+        if let Some(Planter::Plant(plant)) = planters.with_id(planter_id) {
+            keyboard_focus.selected = None;
+            let made_seeds = make_seeds(
+                plant,
+                plant,
+                origin,
+                &sexed_flowers,
+                &mut seeds,
+                &mut seed_pod,
+                &mut status_text_query,
+                &mut seed_created_events,
+            );
+            play_splice_sound(made_seeds, &sound_effects);
+
+            if made_seeds {
+                replay.actions.push(ReplayAction::MakeSeeds {
+                    pollen_planter: planter_id,
+                    pistil_planter: planter_id,
+                    origin,
+                });
+            }
+        }
+        return;
+    }
+
+    if !confirm {
+        return;
+    }
+
+    match (keyboard_focus.selected, focused) {
+        (None, _) => {
+            // only seeds and plants can be picked up, just like when dragging
+            let can_select = match focused {
+                FocusTarget::Seed(seed_id) => seeds.with_id(seed_id).is_some(),
+                FocusTarget::Planter(planter_id) => {
+                    matches!(planters.with_id(planter_id), Some(Planter::Plant(_)))
+                }
+                FocusTarget::PodSeed(_) => false,
+            };
+
+            if can_select {
+                keyboard_focus.selected = Some(focused);
+                sound_effects.play(SoundEffect::PickUp);
+            }
+        }
+        (Some(FocusTarget::Seed(seed_id)), FocusTarget::Planter(planter_id)) => {
+            keyboard_focus.selected = None;
+            if let Some(seed) = seeds.take_with_id(seed_id) {
+                planters.0[planter_id] = Planter::Seed(seed);
+                sound_effects.play(SoundEffect::Plant);
+                seed_planted_events.send(SeedPlanted { planter_id });
+                replay.actions.push(ReplayAction::PlantSeed {
+                    seed: seed_id,
+                    planter: planter_id,
+                });
+            }
+        }
+        (Some(FocusTarget::Planter(source_id)), FocusTarget::Planter(target_id))
+            if source_id != target_id =>
+        {
+            keyboard_focus.selected = None;
+            let swap_plants = keyboard.pressed(KeyCode::LAlt) || keyboard.pressed(KeyCode::RAlt);
+            if move_plant(&mut planters, source_id, target_id, swap_plants) {
+                sound_effects.play(SoundEffect::Drop);
+                replay.actions.push(ReplayAction::MovePlant {
+                    from: source_id,
+                    to: target_id,
+                    swap: swap_plants,
+                });
+            } else if let (Some(Planter::Plant(plant_1)), Some(Planter::Plant(plant_2))) =
+                (planters.with_id(source_id), planters.with_id(target_id))
+            {
+                let made_seeds = make_seeds(
+                    plant_1,
+                    plant_2,
+                    SeedOrigin::Spliced,
+                    &sexed_flowers,
+                    &mut seeds,
+                    &mut seed_pod,
+                    &mut status_text_query,
+                    &mut seed_created_events,
+                );
+                play_splice_sound(made_seeds, &sound_effects);
+
+                if made_seeds {
+                    replay.actions.push(ReplayAction::MakeSeeds {
+                        pollen_planter: source_id,
+                        pistil_planter: target_id,
+                        origin: SeedOrigin::Spliced,
+                    });
+                }
+            }
+        }
+        (Some(_), _) => {
+            // confirming on the selected space again, or somewhere it can't go, puts it back down
+            keyboard_focus.selected = None;
+            sound_effects.play(SoundEffect::Drop);
+        }
+    }
+}
+
+/// Gets which space is next to the provided one in the provided direction.
+fn move_focus(focused: FocusTarget, direction: FocusDirection) -> FocusTarget {
+    match (focused, direction) {
+        (FocusTarget::Seed(id), FocusDirection::Up) => FocusTarget::Seed(id.saturating_sub(1)),
+        (FocusTarget::Seed(id), FocusDirection::Down) => {
+            FocusTarget::Seed((id + 1).min(NUM_SEED_SPACES - 1))
+        }
+        (FocusTarget::Seed(_), FocusDirection::Right) => FocusTarget::Planter(0),
+        (FocusTarget::Planter(0), FocusDirection::Left) => FocusTarget::Seed(0),
+        (FocusTarget::Planter(id), FocusDirection::Left) => FocusTarget::Planter(id - 1),
+        (FocusTarget::Planter(id), FocusDirection::Right) => {
+            FocusTarget::Planter((id + 1).min(NUM_PLANT_SPACES - 1))
+        }
+        (FocusTarget::PodSeed(_), _) => FocusTarget::Planter(0),
+        (focused, _) => focused,
+    }
+}
+
+/// Determines whether the provided button was just pressed on any connected gamepad.
+fn gamepad_just_pressed(
+    gamepads: &Gamepads,
+    gamepad_buttons: &Input<GamepadButton>,
+    button_type: GamepadButtonType,
+) -> bool {
+    gamepads
+        .iter()
+        .any(|gamepad| gamepad_buttons.just_pressed(GamepadButton(*gamepad, button_type)))
+}
+
+type UnhighlightedFilter = Without<Highlight>;
+
+/// Handles showing which spaces are focused and selected with the keyboard or a gamepad.
+fn focus_highlight_system(
+    keyboard_focus: Res<KeyboardFocus>,
+    mut highlight_query: Query<(&mut Transform, &mut Sprite, &mut Visibility, &Highlight)>,
+    plant_space_query: Query<(&Transform, &PlantSpace), UnhighlightedFilter>,
+    seed_space_query: Query<(&Transform, &SeedSpace), UnhighlightedFilter>,
+    mut keep_seed_button_query: Query<(&Interaction, &KeepSeedButton, &mut UiColor)>,
+) {
+    for (mut transform, mut sprite, mut visibility, highlight) in highlight_query.iter_mut() {
+        let target = match highlight {
+            Highlight::Focus => keyboard_focus.focused,
+            Highlight::Selection => keyboard_focus.selected,
+        };
+
+        let space = match target {
+            Some(FocusTarget::Planter(id)) => plant_space_query
+                .iter()
+                .find(|(_, plant_space)| plant_space.0 == id)
+                .map(|(space_transform, _)| {
+                    (
+                        space_transform.translation,
+                        Vec2::new(PLANT_SPACE_SIZE, PLANT_SPACE_HEIGHT),
+                    )
+                }),
+            Some(FocusTarget::Seed(id)) => seed_space_query
+                .iter()
+                .find(|(_, seed_space)| seed_space.0 == id)
+                .map(|(space_transform, _)| {
+                    (
+                        space_transform.translation,
+                        Vec2::new(SEED_SPACE_SIZE, SEED_SPACE_SIZE),
+                    )
+                }),
+            // seeds in the seed pod are highlighted with their buttons instead
+            Some(FocusTarget::PodSeed(_)) | None => None,
+        };
+
+        visibility.is_visible = space.is_some();
+        if let Some((position, size)) = space {
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+            sprite.custom_size = Some(size);
+        }
+    }
+
+    for (interaction, keep_seed_button, mut color) in keep_seed_button_query.iter_mut() {
+        if keyboard_focus.focused == Some(FocusTarget::PodSeed(keep_seed_button.0)) {
+            *color = HOVERED_BUTTON.into();
+        } else if *interaction == Interaction::None {
+            *color = NORMAL_BUTTON.into();
+        }
+    }
+}
+
 /// Handles dropping things that are being dragged.
 fn draggable_drop_system(
     mut commands: Commands,
     mouse_buttons: Res<Input<MouseButton>>,
+    sound_effects: SoundEffects,
     mut dragged_query: Query<(Entity, &mut Transform, &BeingDragged)>,
 ) {
     if !mouse_buttons.pressed(MouseButton::Left) {
         for (entity, mut transform, being_dragged) in dragged_query.iter_mut() {
             transform.translation = being_dragged.original_position;
             commands.entity(entity).remove::<BeingDragged>();
+            if !being_dragged.drop_handled {
+                sound_effects.play(SoundEffect::Drop);
+            }
         }
     }
 }
 
-/// Moves to the win state if the player has won
+/// Logs every game event, which is handy for checking that they're being sent when they should be.
+fn game_event_log_system(
+    mut seed_created_events: EventReader<SeedCreated>,
+    mut seed_planted_events: EventReader<SeedPlanted>,
+    mut plant_grew_events: EventReader<PlantGrew>,
+    mut plant_died_events: EventReader<PlantDied>,
+    mut season_advanced_events: EventReader<SeasonAdvanced>,
+    mut game_won_events: EventReader<GameWon>,
+    mut game_lost_events: EventReader<GameLost>,
+) {
+    for event in seed_created_events.iter() {
+        debug!("{} {} seed(s) created", event.count, event.origin);
+    }
+    for event in seed_planted_events.iter() {
+        debug!("seed planted in planter {}", event.planter_id);
+    }
+    for event in plant_grew_events.iter() {
+        debug!("{} grew in planter {}", event.name, event.planter_id);
+    }
+    for event in plant_died_events.iter() {
+        debug!("{} died ({})", event.name, event.cause_of_death);
+    }
+    for event in season_advanced_events.iter() {
+        debug!("advanced to season {}", event.season);
+    }
+    for event in game_won_events.iter() {
+        debug!("game won in season {}", event.season);
+    }
+    for event in game_lost_events.iter() {
+        debug!("game lost in season {}", event.season);
+    }
+}
+
+/// Finds a plant in the provided planters that's smart enough to win, if there is one.
+pub fn find_smart_plant(planters: &Planters) -> Option<&Plant> {
+    planters.0.iter().find_map(|planter| match planter {
+        Planter::Plant(plant) if plant.get_phenotype().intelligence >= GOAL_INTELLIGENCE => {
+            Some(plant)
+        }
+        _ => None,
+    })
+}
+
+/// Moves to the win state if the player (or one of the players) has won, or the lose state if their rival or another LAN player has beaten them to it
+#[allow(clippy::too_many_arguments)]
 fn check_win_system(
     planters: Res<Planters>,
     mut set_up: ResMut<SetUp>,
     mut smart_plant: ResMut<SmartPlant>,
+    mut rival: ResMut<Rival>,
+    mut hot_seat: ResMut<HotSeat>,
+    mut lan: ResMut<Lan>,
     mut game_state: ResMut<State<GameState>>,
+    season: Res<Season>,
+    mut game_won_events: EventWriter<GameWon>,
+    mut game_lost_events: EventWriter<GameLost>,
 ) {
-    let has_smart_plant = planters.0.iter().any(|planter| {
-        if let Planter::Plant(plant) = planter {
-            if plant.get_phenotype().intelligence >= GOAL_INTELLIGENCE as i32 {
-                smart_plant.0 = Some(plant.clone());
-                true
-            } else {
-                false
-            }
-        } else {
-            false
+    let winner = if hot_seat.is_on() {
+        hot_seat.find_smart_plant(&planters)
+    } else {
+        find_smart_plant(&planters).map(|plant| (0, plant.clone()))
+    };
+
+    // if the player and their rival get there in the same season, the player wins
+    if let Some((player, plant)) = winner {
+        if hot_seat.is_on() {
+            hot_seat.winner = Some(player);
         }
-    });
+        lan.send_win(&plant);
+        smart_plant.0 = Some(plant);
 
-    if has_smart_plant {
         set_up.0 = false;
+        game_won_events.send(GameWon { season: season.0 });
         game_state.overwrite_set(GameState::Win).unwrap();
+        return;
+    }
+
+    // there's no need to check the rival if the player has already lost this season
+    if !set_up.0 {
+        return;
+    }
+
+    let lan_winner = lan
+        .lan_match
+        .as_ref()
+        .filter(|_| lan.is_playing())
+        .and_then(|lan_match| lan_match.winner);
+    if lan_winner.is_some() {
+        set_up.0 = false;
+        game_lost_events.send(GameLost { season: season.0 });
+        game_state.overwrite_set(GameState::Lose).unwrap();
+        return;
+    }
+
+    if let Some(rival_smart_plant) = find_smart_plant(&rival.planters).cloned() {
+        rival.smart_plant = Some(rival_smart_plant);
+        set_up.0 = false;
+        game_lost_events.send(GameLost { season: season.0 });
+        game_state.overwrite_set(GameState::Lose).unwrap();
     }
 }
 
@@ -1405,18 +4860,22 @@ fn check_win_system(
 fn check_lose_system(
     planters: Res<Planters>,
     seeds: Res<Seeds>,
+    hot_seat: Res<HotSeat>,
     mut set_up: ResMut<SetUp>,
     mut game_state: ResMut<State<GameState>>,
+    season: Res<Season>,
+    mut game_lost_events: EventWriter<GameLost>,
 ) {
-    let has_plant_or_planted_seed = planters
-        .0
-        .iter()
-        .any(|planter| matches!(planter, Planter::Plant(_)) || matches!(planter, Planter::Seed(_)));
-
-    let has_seed = !seeds.0.is_empty();
+    // in a hot-seat game, it's only over once nobody has anything left to grow
+    let is_out = if hot_seat.is_on() {
+        hot_seat.everyone_is_out(&planters, &seeds)
+    } else {
+        is_out_of_plants(&planters, &seeds)
+    };
 
-    if !has_plant_or_planted_seed && !has_seed {
+    if is_out {
         set_up.0 = false;
+        game_lost_events.send(GameLost { season: season.0 });
         game_state.overwrite_set(GameState::Lose).unwrap();
     }
 }
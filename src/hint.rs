@@ -0,0 +1,268 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::*;
+
+// how many seed pods to imagine for each thing the plants could be used for, when working out how good it's likely to be
+const HINT_SAMPLE_PODS: usize = 200;
+// hints always imagine the same seeds, so asking twice in the same situation gives the same answer
+const HINT_RNG_SEED: u64 = 0x5eed;
+// how much more likely one option has to be to win before it's considered better, regardless of intelligence
+const WIN_CHANCE_TOLERANCE: f32 = 0.01;
+
+/// Something the player could do next that's likely to get them closer to winning.
+pub enum Hint {
+    /// Make seeds from the plants in the provided planters, with the first plant providing the pollen.
+    MakeSeeds {
+        pollen_planter: usize,
+        pistil_planter: usize,
+        origin: SeedOrigin,
+        outlook: Outlook,
+    },
+    /// Keep the seed at the provided index in the seed pod.
+    KeepSeed { index: usize, outlook: Outlook },
+    /// Get rid of the seed pod, since there's no room to keep any of it.
+    DiscardSeedPod,
+    /// Plant the seed with the provided index in the provided planter.
+    PlantSeed {
+        seed: usize,
+        planter: usize,
+        outlook: Outlook,
+    },
+    /// Move on to the next season, since there's nothing useful left to do in this one.
+    NextSeason,
+}
+
+/// How promising the plant that grows from a seed is likely to be.
+#[derive(Clone, Copy, Default)]
+pub struct Outlook {
+    /// The chance that the plant is smart enough to win and survives the pests.
+    pub win_chance: f32,
+    /// How smart the plant is expected to be, counting plants that don't grow or get eaten as having no intelligence.
+    pub expected_intelligence: f32,
+}
+
+impl Outlook {
+    /// Works out the outlook for the plant that the provided seed would grow into.
+    fn of_seed(seed: &Seed) -> Outlook {
+        if !seed.is_viable() {
+            return Outlook::default();
+        }
+
+        Outlook::of_phenotype(&seed.get_phenotype())
+    }
+
+    /// Works out the outlook for a plant with the provided phenotype.
+    fn of_phenotype(phenotype: &Phenotype) -> Outlook {
+        let survival_chance = 1.0 - get_pest_destruction_chance(phenotype.pest_resistance);
+        let win_chance = if phenotype.intelligence >= GOAL_INTELLIGENCE {
+            survival_chance
+        } else {
+            0.0
+        };
+
+        Outlook {
+            win_chance,
+            expected_intelligence: survival_chance * phenotype.intelligence.max(0) as f32,
+        }
+    }
+
+    /// Determines whether this outlook is better than the provided one.
+    /// A better chance of winning right away counts for more than being smarter.
+    fn is_better_than(&self, other: &Outlook) -> bool {
+        if (self.win_chance - other.win_chance).abs() > WIN_CHANCE_TOLERANCE {
+            self.win_chance > other.win_chance
+        } else {
+            self.expected_intelligence > other.expected_intelligence
+        }
+    }
+}
+
+/// Searches through everything the player could do with their plants and seeds, and picks what's most likely to help them win.
+/// Seeds are imagined with a separate random number generator, so asking for a hint doesn't change what happens in the game.
+pub fn find_hint(
+    planters: &Planters,
+    seeds: &Seeds,
+    seed_pod: &[Seed],
+    sexed_flowers: bool,
+) -> Hint {
+    let has_room_for_seeds = seeds.0.len() < NUM_SEED_SPACES;
+
+    // seeds in the pod have to be dealt with before anything else can be spliced
+    if !seed_pod.is_empty() {
+        if !has_room_for_seeds {
+            return Hint::DiscardSeedPod;
+        }
+
+        return best_seed(seed_pod)
+            .map(|(index, outlook)| Hint::KeepSeed { index, outlook })
+            .unwrap_or(Hint::DiscardSeedPod);
+    }
+
+    let mut best_hint = None;
+    let mut best_outlook = Outlook::default();
+
+    if let Some(planter) = find_free_planter(planters) {
+        if let Some((seed, outlook)) = best_seed(&seeds.0) {
+            best_hint = Some(Hint::PlantSeed {
+                seed,
+                planter,
+                outlook,
+            });
+            best_outlook = outlook;
+        }
+    }
+
+    if has_room_for_seeds {
+        let mut rng = StdRng::seed_from_u64(HINT_RNG_SEED);
+        for (pollen_planter, pistil_planter, origin) in
+            get_possible_crosses(planters, sexed_flowers)
+        {
+            let outlook = match (&planters.0[pollen_planter], &planters.0[pistil_planter]) {
+                (Planter::Plant(plant_1), Planter::Plant(plant_2)) => {
+                    imagine_outlook(plant_1, plant_2, origin, &mut rng)
+                }
+                _ => continue,
+            };
+
+            if best_hint.is_none() || outlook.is_better_than(&best_outlook) {
+                best_hint = Some(Hint::MakeSeeds {
+                    pollen_planter,
+                    pistil_planter,
+                    origin,
+                    outlook,
+                });
+                best_outlook = outlook;
+            }
+        }
+    }
+
+    best_hint.unwrap_or(Hint::NextSeason)
+}
+
+/// Finds a seed that's more promising than one of the living plants, so it's worth planting in its place.
+/// Returns the index of the seed, and the planter with the least promising plant.
+pub fn find_replacement(planters: &Planters, seeds: &Seeds) -> Option<(usize, usize)> {
+    let (seed, seed_outlook) = best_seed(&seeds.0)?;
+
+    let (planter, plant_outlook) = planters
+        .0
+        .iter()
+        .enumerate()
+        .filter_map(|(index, planter)| match planter {
+            Planter::Plant(plant) => Some((index, Outlook::of_phenotype(&plant.get_phenotype()))),
+            _ => None,
+        })
+        .reduce(|worst, current| {
+            if worst.1.is_better_than(&current.1) {
+                current
+            } else {
+                worst
+            }
+        })?;
+
+    seed_outlook
+        .is_better_than(&plant_outlook)
+        .then_some((seed, planter))
+}
+
+/// Finds the seed with the best outlook in the provided seeds, returning its index and outlook.
+fn best_seed(seeds: &[Seed]) -> Option<(usize, Outlook)> {
+    seeds
+        .iter()
+        .map(Outlook::of_seed)
+        .enumerate()
+        .reduce(|best, current| {
+            if current.1.is_better_than(&best.1) {
+                current
+            } else {
+                best
+            }
+        })
+}
+
+/// Finds a planter that a seed could be planted in without getting rid of anything that's still alive.
+fn find_free_planter(planters: &Planters) -> Option<usize> {
+    planters.0.iter().position(|planter| {
+        matches!(
+            planter,
+            Planter::Empty | Planter::DeadPlant(_, _) | Planter::FailedSeed(_)
+        )
+    })
+}
+
+/// Gets every way seeds could be made from the living plants, as the pollen planter, pistil planter and origin.
+/// Anything that would be rejected for being sterile or not having the right flowers is left out.
+fn get_possible_crosses(
+    planters: &Planters,
+    sexed_flowers: bool,
+) -> Vec<(usize, usize, SeedOrigin)> {
+    let plants = planters
+        .0
+        .iter()
+        .enumerate()
+        .filter_map(|(index, planter)| match planter {
+            Planter::Plant(plant) => Some((index, plant)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut crosses = Vec::new();
+    for &(index_1, plant_1) in &plants {
+        crosses.push((index_1, index_1, SeedOrigin::Cutting));
+
+        if plant_1.is_sterile() {
+            continue;
+        }
+
+        if !sexed_flowers || plant_1.can_pollinate(plant_1) {
+            crosses.push((index_1, index_1, SeedOrigin::SelfPollinated));
+        }
+
+        for &(index_2, plant_2) in &plants {
+            if index_1 == index_2 || plant_2.is_sterile() {
+                continue;
+            }
+
+            // without flower sexes it doesn't matter which plant is dragged onto which
+            let can_splice = if sexed_flowers {
+                plant_1.can_pollinate(plant_2)
+            } else {
+                index_1 < index_2
+            };
+            if can_splice {
+                crosses.push((index_1, index_2, SeedOrigin::Spliced));
+            }
+        }
+    }
+
+    crosses
+}
+
+/// Imagines lots of seed pods from the provided plants, and works out the average outlook for the best seed in each one.
+fn imagine_outlook(
+    plant_1: &Plant,
+    plant_2: &Plant,
+    origin: SeedOrigin,
+    rng: &mut StdRng,
+) -> Outlook {
+    // a cutting always grows into the same plant, so there's nothing to imagine
+    if origin == SeedOrigin::Cutting {
+        return Outlook::of_seed(&take_cutting(plant_1));
+    }
+
+    let mut total = Outlook::default();
+    for _ in 0..HINT_SAMPLE_PODS {
+        let pod = (0..SEEDS_PER_POD)
+            .map(|_| imagine_seed(plant_1, plant_2, origin, rng))
+            .collect::<Vec<_>>();
+        if let Some((_, outlook)) = best_seed(&pod) {
+            total.win_chance += outlook.win_chance;
+            total.expected_intelligence += outlook.expected_intelligence;
+        }
+    }
+
+    Outlook {
+        win_chance: total.win_chance / HINT_SAMPLE_PODS as f32,
+        expected_intelligence: total.expected_intelligence / HINT_SAMPLE_PODS as f32,
+    }
+}
@@ -0,0 +1,1064 @@
+use crate::*;
+
+pub const MAX_PLAYERS: usize = 4;
+
+pub struct HotSeatPlugin;
+
+impl Plugin for HotSeatPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_enter(GameState::Game)
+                .with_system(multiplayer_setup.after(GameSystem::Setup)),
+        )
+        .add_system(
+            hot_seat_turn_system
+                .with_run_criteria(is_set_up)
+                .after(GameSystem::Input)
+                .before(GameSystem::SeasonTransition),
+        )
+        .add_system(
+            start_trading_system
+                .with_run_criteria(is_set_up)
+                .after(GameSystem::SeasonTransition),
+        )
+        .add_system(current_player_text_system.with_run_criteria(is_set_up))
+        .add_system(trading_screen_display_system.with_run_criteria(is_set_up))
+        .add_system(trade_item_button_system)
+        .add_system(trade_recipient_button_system)
+        .add_system(trade_accept_button_system)
+        .add_system(trade_decline_button_system)
+        .add_system(trade_done_button_system)
+        .insert_resource(HotSeat {
+            num_players: 1,
+            ..default()
+        });
+    }
+}
+
+#[derive(Component)]
+struct CurrentPlayerText;
+
+#[derive(Component)]
+struct TradingScreen;
+
+#[derive(Component)]
+struct TradeItemButton(TradeSelection);
+
+#[derive(Component)]
+struct TradeRecipientButton(usize);
+
+#[derive(Component)]
+struct TradeAcceptButton(usize);
+
+#[derive(Component)]
+struct TradeDeclineButton(usize);
+
+#[derive(Component)]
+struct TradeDoneButton;
+
+/// One player's plants and seeds, and what's happened to them each season.
+#[derive(Default)]
+pub struct Farm {
+    pub planters: Planters,
+    pub seeds: Seeds,
+    pub season_log: SeasonLog,
+}
+
+/// Something one player has offered to give another between seasons.
+pub struct TradeOffer {
+    pub from: usize,
+    pub to: usize,
+    pub seed: Seed,
+    /// Whether the seed came out of the giver's seed tray, so it should go back there if the offer is declined.
+    /// Cuttings don't go back anywhere, since the giver still has the plant they came from.
+    pub from_seed_tray: bool,
+}
+
+/// Something the trading player has picked to offer.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TradeSelection {
+    /// The seed at the provided index in their seed tray.
+    Seed(usize),
+    /// A cutting from the plant in the provided planter.
+    Cutting(usize),
+}
+
+/// The trading phase between seasons, where players take turns offering each other seeds and cuttings.
+pub struct Trading {
+    /// The player whose turn it is to trade.
+    pub trader: usize,
+    /// Whether every player has had a turn to make offers, so all that's left is answering them.
+    pub answering_only: bool,
+    /// What the trader has picked to offer, if anything.
+    pub selected: Option<TradeSelection>,
+    pub offers: Vec<TradeOffer>,
+}
+
+/// Several players taking turns on the same computer, each with their own farm but sharing the seasons.
+#[derive(Default)]
+pub struct HotSeat {
+    /// How many players there are, from the menu. There's only hot-seat play when there's more than one.
+    pub num_players: usize,
+    /// Every player's farm. Whoever's turn it is has their farm in the `Planters`, `Seeds` and `SeasonLog` resources,
+    /// so their farm here is left empty until their turn ends.
+    pub farms: Vec<Farm>,
+    /// The player whose farm is in the `Planters`, `Seeds` and `SeasonLog` resources, if anyone's is.
+    pub current_player: Option<usize>,
+    /// The trading phase, if it's happening.
+    pub trading: Option<Trading>,
+    /// The player who grew a smart enough plant first, once someone has.
+    pub winner: Option<usize>,
+}
+
+impl HotSeat {
+    /// Whether the game that's being played is a hot-seat game.
+    pub fn is_on(&self) -> bool {
+        !self.farms.is_empty()
+    }
+
+    pub fn is_trading(&self) -> bool {
+        self.trading.is_some()
+    }
+
+    /// Starts a hot-seat game where every player has a copy of the provided plants, and the first player goes first.
+    pub fn start(&mut self, planters: &Planters) {
+        self.farms = (0..self.num_players)
+            .map(|player| Farm {
+                // the first player's plants are the ones that are already out
+                planters: if player == 0 {
+                    Planters::default()
+                } else {
+                    planters.clone()
+                },
+                seeds: Seeds::default(),
+                season_log: SeasonLog::default(),
+            })
+            .collect();
+        self.current_player = Some(0);
+        self.trading = None;
+        self.winner = None;
+    }
+
+    /// Stops any hot-seat game, so the next game is single player.
+    pub fn stop(&mut self) {
+        self.farms.clear();
+        self.current_player = None;
+        self.trading = None;
+        self.winner = None;
+    }
+
+    /// Puts the current player's farm away, and gets out the provided player's farm, if there is one.
+    /// Getting out a farm changes the season log, which shows the player what happened to it last season.
+    pub fn switch_to(
+        &mut self,
+        player: Option<usize>,
+        planters: &mut Planters,
+        seeds: &mut Seeds,
+        season_log: &mut SeasonLog,
+    ) {
+        if let Some(farm) = self
+            .current_player
+            .and_then(|current| self.farms.get_mut(current))
+        {
+            std::mem::swap(planters, &mut farm.planters);
+            std::mem::swap(seeds, &mut farm.seeds);
+            std::mem::swap(season_log, &mut farm.season_log);
+        }
+
+        self.current_player = player;
+
+        if let Some(farm) = player.and_then(|player| self.farms.get_mut(player)) {
+            std::mem::swap(planters, &mut farm.planters);
+            std::mem::swap(seeds, &mut farm.seeds);
+            std::mem::swap(season_log, &mut farm.season_log);
+        }
+    }
+
+    /// Finds the next player after the current one this season who still has something to grow.
+    pub fn next_player_this_season(&self) -> Option<usize> {
+        let current = self.current_player?;
+        (current + 1..self.farms.len()).find(|&player| !self.is_player_out(player))
+    }
+
+    /// Finds the first player who still has something to grow, to start a new season with.
+    pub fn first_player(&self) -> Option<usize> {
+        (0..self.farms.len()).find(|&player| !self.is_player_out(player))
+    }
+
+    /// Determines whether the provided player has nothing left to grow.
+    /// This only works for players whose farms are put away.
+    fn is_player_out(&self, player: usize) -> bool {
+        self.farms
+            .get(player)
+            .map_or(true, |farm| is_out_of_plants(&farm.planters, &farm.seeds))
+    }
+
+    /// Determines whether every player has nothing left to grow, using the provided plants and seeds for the current player.
+    pub fn everyone_is_out(&self, planters: &Planters, seeds: &Seeds) -> bool {
+        (0..self.farms.len()).all(|player| {
+            if Some(player) == self.current_player {
+                is_out_of_plants(planters, seeds)
+            } else {
+                self.is_player_out(player)
+            }
+        })
+    }
+
+    /// Finds the first player with a plant that's smart enough to win, using the provided plants for the current player.
+    /// Returns the player and their plant.
+    pub fn find_smart_plant(&self, planters: &Planters) -> Option<(usize, Plant)> {
+        (0..self.farms.len()).find_map(|player| {
+            let farm_planters = if Some(player) == self.current_player {
+                planters
+            } else {
+                &self.farms[player].planters
+            };
+            find_smart_plant(farm_planters).map(|plant| (player, plant.clone()))
+        })
+    }
+
+    /// Grows the seeds and plants of every player whose farm is put away, and lets the pests at them.
+    /// What happened is added to each farm's season log, for the player to see when their farm is next got out.
+    /// Returns the reports, along with the IDs of the planters where seeds sprouted and the names of the plants that grew there.
+    pub fn grow_waiting_farms(
+        &mut self,
+        season: u32,
+    ) -> Vec<(&SeasonReport, Vec<(usize, PlantName)>)> {
+        let current_player = self.current_player;
+        self.farms
+            .iter_mut()
+            .enumerate()
+            .filter(|(player, _)| Some(*player) != current_player)
+            .filter_map(|(_, farm)| {
+                let planted_seeds = farm
+                    .planters
+                    .0
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, planter)| matches!(planter, Planter::Seed(_)))
+                    .map(|(id, _)| id)
+                    .collect::<Vec<_>>();
+
+                let mut report = SeasonReport {
+                    season,
+                    ..farm.planters.grow()
+                };
+                let sprouted = planted_seeds
+                    .into_iter()
+                    .filter_map(|id| match farm.planters.0.get(id) {
+                        Some(Planter::Plant(plant)) => Some((id, plant.name.clone())),
+                        _ => None,
+                    })
+                    .collect();
+
+                let pest_victims = farm.planters.choose_pest_victims();
+                farm.planters.unleash_pests(&pest_victims, &mut report);
+                report.stats = farm.planters.get_phenotype_stats();
+
+                farm.season_log.0.push(report);
+                farm.season_log.0.last().map(|report| (report, sprouted))
+            })
+            .collect()
+    }
+
+    /// Puts the current player's farm away, and starts the trading phase with the first player.
+    pub fn start_trading(
+        &mut self,
+        planters: &mut Planters,
+        seeds: &mut Seeds,
+        season_log: &mut SeasonLog,
+    ) {
+        self.switch_to(None, planters, seeds, season_log);
+        self.trading = Some(Trading {
+            trader: 0,
+            answering_only: false,
+            selected: None,
+            offers: Vec::new(),
+        });
+    }
+
+    /// Gets the seeds the trading player could offer.
+    pub fn get_tradeable_seeds(&self) -> Vec<(TradeSelection, Seed)> {
+        match self
+            .trading
+            .as_ref()
+            .and_then(|trading| self.farms.get(trading.trader))
+        {
+            Some(farm) => get_tradeable_seeds(&farm.planters, &farm.seeds),
+            None => Vec::new(),
+        }
+    }
+
+    /// Offers whatever the trading player has picked to the provided player.
+    pub fn make_offer(&mut self, to: usize) {
+        let trading = match &mut self.trading {
+            Some(trading) => trading,
+            None => return,
+        };
+        let farm = match self.farms.get_mut(trading.trader) {
+            Some(farm) => farm,
+            None => return,
+        };
+
+        let offer = trading
+            .selected
+            .take()
+            .and_then(|selection| take_trade_selection(selection, &farm.planters, &mut farm.seeds));
+
+        if let Some((seed, from_seed_tray)) = offer {
+            trading.offers.push(TradeOffer {
+                from: trading.trader,
+                to,
+                seed,
+                from_seed_tray,
+            });
+        }
+    }
+
+    /// Gives the provided player the seed from the offer with the provided index, if they have room for it.
+    /// Returns whether the offer was accepted.
+    pub fn accept_offer(&mut self, index: usize) -> bool {
+        let trading = match &mut self.trading {
+            Some(trading) => trading,
+            None => return false,
+        };
+
+        let has_room = trading
+            .offers
+            .get(index)
+            .and_then(|offer| self.farms.get(offer.to))
+            .map_or(false, |farm| farm.seeds.0.len() < NUM_SEED_SPACES);
+        if !has_room {
+            return false;
+        }
+
+        let offer = trading.offers.remove(index);
+        self.farms[offer.to].seeds.0.push(offer.seed);
+        true
+    }
+
+    /// Turns down the offer with the provided index, giving the seed back if it came from a seed tray.
+    pub fn decline_offer(&mut self, index: usize) {
+        let trading = match &mut self.trading {
+            Some(trading) => trading,
+            None => return,
+        };
+
+        if index >= trading.offers.len() {
+            return;
+        }
+
+        let offer = trading.offers.remove(index);
+        if offer.from_seed_tray {
+            if let Some(farm) = self.farms.get_mut(offer.from) {
+                // if the giver has filled up their seed tray since, the seed is lost
+                if farm.seeds.0.len() < NUM_SEED_SPACES {
+                    farm.seeds.0.push(offer.seed);
+                }
+            }
+        }
+    }
+
+    /// Ends the trading player's turn, turning down any offers to them they haven't answered.
+    /// Once everyone has made their offers, players who still have offers to answer get another turn.
+    /// When there's nothing left to answer, trading ends and the first player's farm is got out for the new season.
+    pub fn finish_trading_turn(
+        &mut self,
+        planters: &mut Planters,
+        seeds: &mut Seeds,
+        season_log: &mut SeasonLog,
+    ) {
+        let trader = match &self.trading {
+            Some(trading) => trading.trader,
+            None => return,
+        };
+
+        while let Some(index) = self
+            .trading
+            .as_ref()
+            .and_then(|trading| trading.offers.iter().position(|offer| offer.to == trader))
+        {
+            self.decline_offer(index);
+        }
+
+        let num_players = self.farms.len();
+        if let Some(trading) = &mut self.trading {
+            trading.selected = None;
+
+            if !trading.answering_only && trader + 1 < num_players {
+                trading.trader = trader + 1;
+                return;
+            }
+
+            trading.answering_only = true;
+            if let Some(offer) = trading.offers.first() {
+                trading.trader = offer.to;
+                return;
+            }
+        }
+
+        self.trading = None;
+        let first_player = self.first_player();
+        self.switch_to(first_player, planters, seeds, season_log);
+    }
+}
+
+/// Gets the seeds that could be offered from the provided plants and seeds, with the seed tray first and then cuttings from the plants.
+pub fn get_tradeable_seeds(planters: &Planters, seeds: &Seeds) -> Vec<(TradeSelection, Seed)> {
+    let tray_seeds = seeds
+        .0
+        .iter()
+        .enumerate()
+        .map(|(index, seed)| (TradeSelection::Seed(index), seed.clone()));
+    let cuttings = planters
+        .0
+        .iter()
+        .enumerate()
+        .filter_map(|(index, planter)| match planter {
+            Planter::Plant(plant) => Some((TradeSelection::Cutting(index), take_cutting(plant))),
+            _ => None,
+        });
+
+    tray_seeds.chain(cuttings).collect()
+}
+
+/// Gets the seed for something that's been picked to offer, taking it out of the seed tray if that's where it is.
+/// Returns the seed, and whether it came from the seed tray.
+pub fn take_trade_selection(
+    selection: TradeSelection,
+    planters: &Planters,
+    seeds: &mut Seeds,
+) -> Option<(Seed, bool)> {
+    match selection {
+        TradeSelection::Seed(index) if index < seeds.0.len() => Some((seeds.0.remove(index), true)),
+        TradeSelection::Cutting(index) => match planters.0.get(index) {
+            Some(Planter::Plant(plant)) => Some((take_cutting(plant), false)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Starts a hot-seat game if there's more than one player, with a copy of the starting plants for each of them.
+/// Hot-seat games and LAN matches also get the text showing whose turn it is, and the trading screen.
+fn multiplayer_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    planters: Res<Planters>,
+    playback: Res<ReplayPlayback>,
+    lan: Res<Lan>,
+    mut hot_seat: ResMut<HotSeat>,
+) {
+    // replays are always of single player games, and in a LAN match every player has their own game
+    if hot_seat.num_players < 2 || playback.is_running() || lan.is_playing() {
+        hot_seat.stop();
+    } else {
+        hot_seat.start(&planters);
+    }
+
+    if !hot_seat.is_on() && !lan.is_playing() {
+        return;
+    }
+
+    let main_font = asset_server.load(MAIN_FONT);
+
+    // whose turn it is, between the seeds heading and the seeds so it stays clear of the bars
+    commands
+        .spawn_bundle(Text2dBundle {
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font: main_font.clone(),
+                    font_size: 25.0,
+                    color: Color::ORANGE,
+                },
+            )
+            .with_alignment(TextAlignment::CENTER),
+            transform: Transform {
+                translation: Vec3::new(
+                    SEEDS_SECTION_START_X + (SEEDS_SECTION_WIDTH / 2.0),
+                    (SEEDS_SECTION_HEIGHT / 2.0) - (SECTION_MARGIN * 3.0),
+                    MIDDLE_LAYER,
+                ),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(GameComponent)
+        .insert(CurrentPlayerText);
+
+    // trading screen, which is filled in when trading starts
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(90.0), Val::Percent(80.0)),
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    left: Val::Percent(5.0),
+                    top: Val::Percent(10.0),
+                    ..default()
+                },
+                flex_direction: FlexDirection::ColumnReverse,
+                justify_content: JustifyContent::FlexStart,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            color: Color::rgba(0.1, 0.1, 0.1, 0.99).into(),
+            visibility: Visibility { is_visible: false },
+            ..default()
+        })
+        .insert(GameComponent)
+        .insert(TradingScreen);
+}
+
+/// Passes the turn on to the next player when the current one moves on to the next season, until everyone has had a turn.
+/// Once the last player moves on, everyone else's farms move on to the next season along with theirs.
+#[allow(clippy::too_many_arguments)]
+fn hot_seat_turn_system(
+    season: Res<Season>,
+    mut season_transition: ResMut<SeasonTransition>,
+    mut hot_seat: ResMut<HotSeat>,
+    mut planters: ResMut<Planters>,
+    mut seeds: ResMut<Seeds>,
+    mut season_log: ResMut<SeasonLog>,
+    mut seed_pod: ResMut<SeedPod>,
+    mut keyboard_focus: ResMut<KeyboardFocus>,
+    mut status_text_query: Query<&mut Text, StatusTextFilter>,
+    mut plant_grew_events: EventWriter<PlantGrew>,
+    mut plant_died_events: EventWriter<PlantDied>,
+) {
+    if !hot_seat.is_on() || !season_transition.is_starting() {
+        return;
+    }
+
+    // the season can't change until everyone's done trading
+    if hot_seat.is_trading() {
+        season_transition.cancel();
+        return;
+    }
+
+    match hot_seat.next_player_this_season() {
+        Some(next_player) => {
+            // anything left in the seed pod belonged to the player whose turn it was
+            seed_pod.0.clear();
+            keyboard_focus.clear_selection();
+
+            hot_seat.switch_to(
+                Some(next_player),
+                &mut planters,
+                &mut seeds,
+                &mut season_log,
+            );
+            season_transition.cancel();
+            show_status_message(
+                &mut status_text_query,
+                format!("Player {}'s turn", next_player + 1),
+            );
+        }
+        None => {
+            // the season is moved on later this frame, by the season transition
+            for (report, sprouted) in hot_seat.grow_waiting_farms(season.0 + 1) {
+                for (planter_id, name) in sprouted {
+                    plant_grew_events.send(PlantGrew { planter_id, name });
+                }
+                for (name, cause_of_death) in &report.deaths {
+                    plant_died_events.send(PlantDied {
+                        name: name.clone(),
+                        cause_of_death: *cause_of_death,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Starts trading between the players once a hot-seat game or LAN match has moved on to the next season.
+#[allow(clippy::too_many_arguments)]
+fn start_trading_system(
+    mut season_advanced_events: EventReader<SeasonAdvanced>,
+    mut hot_seat: ResMut<HotSeat>,
+    mut lan: ResMut<Lan>,
+    mut planters: ResMut<Planters>,
+    mut seeds: ResMut<Seeds>,
+    mut season_log: ResMut<SeasonLog>,
+    mut seed_pod: ResMut<SeedPod>,
+    mut keyboard_focus: ResMut<KeyboardFocus>,
+) {
+    if season_advanced_events.iter().count() == 0 {
+        return;
+    }
+
+    if hot_seat.is_on() {
+        seed_pod.0.clear();
+        keyboard_focus.clear_selection();
+        hot_seat.start_trading(&mut planters, &mut seeds, &mut season_log);
+    } else if lan.is_playing() {
+        seed_pod.0.clear();
+        keyboard_focus.clear_selection();
+        lan.start_trading();
+    }
+}
+
+/// Keeps the text showing whose turn it is up to date.
+fn current_player_text_system(
+    hot_seat: Res<HotSeat>,
+    lan: Res<Lan>,
+    mut text_query: Query<&mut Text, With<CurrentPlayerText>>,
+) {
+    if !hot_seat.is_changed() && !lan.is_changed() {
+        return;
+    }
+
+    let current_player_text = if lan.is_playing() {
+        let player = lan.player.unwrap_or_default() + 1;
+        if lan.is_trading() {
+            format!("Player {player} is trading")
+        } else if lan.is_between_turns() {
+            format!("Player {player} is waiting")
+        } else {
+            format!("Player {player}'s turn")
+        }
+    } else {
+        match (&hot_seat.trading, hot_seat.current_player) {
+            (Some(trading), _) => format!("Player {} is trading", trading.trader + 1),
+            (None, Some(player)) => format!("Player {}'s turn", player + 1),
+            (None, None) => String::new(),
+        }
+    };
+
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = current_player_text.clone();
+    }
+}
+
+/// Shows the trading screen while players are trading, and fills it in with the trading player's offers and options.
+fn trading_screen_display_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    hot_seat: Res<HotSeat>,
+    lan: Res<Lan>,
+    planters: Res<Planters>,
+    seeds: Res<Seeds>,
+    mut trading_screen_query: Query<(Entity, &mut Visibility), With<TradingScreen>>,
+) {
+    // in a LAN match, the player's own plants and seeds are what they can offer
+    let farm_changed = lan.is_trading() && (planters.is_changed() || seeds.is_changed());
+    if !hot_seat.is_changed() && !lan.is_changed() && !farm_changed {
+        return;
+    }
+
+    let view = get_hot_seat_trading_view(&hot_seat)
+        .or_else(|| get_lan_trading_view(&lan, &planters, &seeds));
+
+    let main_font = asset_server.load(MAIN_FONT);
+    let title_font = asset_server.load(TITLE_FONT);
+
+    for (entity, mut visibility) in trading_screen_query.iter_mut() {
+        visibility.is_visible = view.is_some();
+
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|parent| {
+                if let Some(view) = &view {
+                    spawn_trading_entries(parent, view, &main_font, &title_font);
+                }
+            });
+    }
+}
+
+/// Everything the trading screen shows the trading player, whether they're trading with hot-seat players or over LAN.
+struct TradingView {
+    player: usize,
+    num_players: usize,
+    /// Offers to the player, as the index they're answered by, who they're from, and the seed.
+    incoming_offers: Vec<(usize, usize, Seed)>,
+    /// Offers the player is waiting to hear back about, as who they're for, and the seed.
+    outgoing_offers: Vec<(usize, Seed)>,
+    /// What the player could offer, if they can still make offers.
+    tradeable_seeds: Option<Vec<(TradeSelection, Seed)>>,
+    selected: Option<TradeSelection>,
+    /// Why the player can't make offers any more, if they can't.
+    message: Option<&'static str>,
+    can_finish: bool,
+}
+
+/// Gets what the trading screen shows while hot-seat players are trading.
+fn get_hot_seat_trading_view(hot_seat: &HotSeat) -> Option<TradingView> {
+    let trading = hot_seat.trading.as_ref()?;
+
+    Some(TradingView {
+        player: trading.trader,
+        num_players: hot_seat.farms.len(),
+        incoming_offers: trading
+            .offers
+            .iter()
+            .enumerate()
+            .filter(|(_, offer)| offer.to == trading.trader)
+            .map(|(index, offer)| (index, offer.from, offer.seed.clone()))
+            .collect(),
+        outgoing_offers: trading
+            .offers
+            .iter()
+            .filter(|offer| offer.from == trading.trader)
+            .map(|offer| (offer.to, offer.seed.clone()))
+            .collect(),
+        tradeable_seeds: (!trading.answering_only).then(|| hot_seat.get_tradeable_seeds()),
+        selected: trading.selected,
+        message: trading
+            .answering_only
+            .then_some("Everyone has made their offers, so all that's left is to answer yours."),
+        can_finish: true,
+    })
+}
+
+/// Gets what the trading screen shows while trading in a LAN match.
+fn get_lan_trading_view(lan: &Lan, planters: &Planters, seeds: &Seeds) -> Option<TradingView> {
+    if !lan.is_trading() {
+        return None;
+    }
+
+    let lan_match = lan.lan_match.as_ref()?;
+    let trading = lan_match.trading.as_ref()?;
+
+    Some(TradingView {
+        player: lan.player.unwrap_or_default(),
+        num_players: lan_match.num_players,
+        incoming_offers: lan_match
+            .incoming_offers
+            .iter()
+            .enumerate()
+            .map(|(index, offer)| (index, offer.from, offer.seed.clone()))
+            .collect(),
+        outgoing_offers: lan_match
+            .outgoing_offers
+            .iter()
+            .map(|offer| (offer.to, offer.seed.clone()))
+            .collect(),
+        tradeable_seeds: (!trading.done).then(|| get_tradeable_seeds(planters, seeds)),
+        selected: trading.selected,
+        message: trading
+            .done
+            .then_some("Waiting for the other players to finish trading..."),
+        can_finish: !trading.done,
+    })
+}
+
+/// Adds everything the trading player can see and do to the trading screen.
+fn spawn_trading_entries(
+    parent: &mut ChildBuilder,
+    view: &TradingView,
+    main_font: &Handle<Font>,
+    title_font: &Handle<Font>,
+) {
+    let text_style = TextStyle {
+        font: main_font.clone(),
+        font_size: 20.0,
+        color: Color::WHITE,
+    };
+
+    // title
+    parent.spawn_bundle(
+        TextBundle::from_section(
+            format!("Trading: Player {}", view.player + 1),
+            TextStyle {
+                font: title_font.clone(),
+                font_size: 40.0,
+                color: Color::WHITE,
+            },
+        )
+        .with_style(Style {
+            margin: UiRect::all(Val::Px(10.0)),
+            ..default()
+        }),
+    );
+
+    // offers to the trading player
+    for (index, from, seed) in &view.incoming_offers {
+        parent
+            .spawn_bundle(NodeBundle {
+                style: Style {
+                    align_items: AlignItems::Center,
+                    margin: UiRect::all(Val::Px(5.0)),
+                    ..default()
+                },
+                color: UiColor(Color::NONE),
+                ..default()
+            })
+            .with_children(|parent| {
+                parent.spawn_bundle(TextBundle::from_section(
+                    format!(
+                        "Player {} offers you {}",
+                        from + 1,
+                        get_trade_seed_text(seed)
+                    ),
+                    text_style.clone(),
+                ));
+                spawn_trading_button(
+                    parent,
+                    "Accept",
+                    Color::SEA_GREEN,
+                    main_font,
+                    TradeAcceptButton(*index),
+                );
+                spawn_trading_button(
+                    parent,
+                    "Decline",
+                    Color::SEA_GREEN,
+                    main_font,
+                    TradeDeclineButton(*index),
+                );
+            });
+    }
+
+    if let Some(message) = view.message {
+        parent.spawn_bundle(TextBundle::from_section(message, text_style.clone()));
+    }
+
+    if let Some(tradeable_seeds) = &view.tradeable_seeds {
+        parent.spawn_bundle(
+            TextBundle::from_section(
+                "Pick a seed, or a cutting from one of your plants, to offer to another player:",
+                text_style.clone(),
+            )
+            .with_style(Style {
+                margin: UiRect::all(Val::Px(10.0)),
+                ..default()
+            }),
+        );
+
+        for (selection, seed) in tradeable_seeds {
+            let color = if view.selected == Some(*selection) {
+                Color::ORANGE
+            } else {
+                Color::SEA_GREEN
+            };
+            let seed_text = get_trade_seed_text(seed);
+            spawn_trading_button(
+                parent,
+                &format!("Offer {seed_text}"),
+                color,
+                main_font,
+                TradeItemButton(*selection),
+            );
+        }
+
+        if view.selected.is_some() {
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        margin: UiRect::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    color: UiColor(Color::NONE),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for player in (0..view.num_players).filter(|&player| player != view.player) {
+                        spawn_trading_button(
+                            parent,
+                            &format!("Give to Player {}", player + 1),
+                            Color::SEA_GREEN,
+                            main_font,
+                            TradeRecipientButton(player),
+                        );
+                    }
+                });
+        }
+    }
+
+    // offers the trading player is waiting to hear back about
+    for (to, seed) in &view.outgoing_offers {
+        parent.spawn_bundle(TextBundle::from_section(
+            format!(
+                "Waiting for Player {} to answer your offer of {}",
+                to + 1,
+                get_trade_seed_text(seed)
+            ),
+            text_style.clone(),
+        ));
+    }
+
+    if view.can_finish {
+        spawn_trading_button(parent, "Done", Color::SEA_GREEN, main_font, TradeDoneButton);
+    }
+}
+
+/// Adds a button with the provided text to the trading screen.
+fn spawn_trading_button(
+    parent: &mut ChildBuilder,
+    text: &str,
+    text_color: Color,
+    font: &Handle<Font>,
+    component: impl Component,
+) {
+    parent
+        .spawn_bundle(ButtonBundle {
+            style: Style {
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(5.0)),
+                padding: UiRect::all(Val::Px(5.0)),
+                ..default()
+            },
+            color: NORMAL_BUTTON.into(),
+            ..default()
+        })
+        .insert(component)
+        .with_children(|parent| {
+            parent.spawn_bundle(TextBundle::from_section(
+                text,
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 20.0,
+                    color: text_color,
+                },
+            ));
+        });
+}
+
+/// Describes a seed that's up for trade, including what the plant it grows into will be like.
+fn get_trade_seed_text(seed: &Seed) -> String {
+    let phenotype = seed.get_phenotype();
+    let kind = match seed.origin {
+        SeedOrigin::Cutting => format!("a cutting of {}", seed.parent_name_1),
+        SeedOrigin::Spliced | SeedOrigin::SelfPollinated => "a seed".to_string(),
+    };
+
+    format!(
+        "{} ({} intelligence, {} pest resistance)",
+        kind, phenotype.intelligence, phenotype.pest_resistance
+    )
+}
+
+type InteractedTradeItemButtonTuple = (Changed<Interaction>, With<TradeItemButton>);
+
+/// Handles picking something to offer on the trading screen.
+fn trade_item_button_system(
+    mut hot_seat: ResMut<HotSeat>,
+    mut lan: ResMut<Lan>,
+    interaction_query: Query<(&Interaction, &TradeItemButton), InteractedTradeItemButtonTuple>,
+) {
+    for (interaction, trade_item_button) in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            if let Some(trading) = &mut hot_seat.trading {
+                trading.selected = Some(trade_item_button.0);
+            } else if let Some(trading) = lan
+                .lan_match
+                .as_mut()
+                .and_then(|lan_match| lan_match.trading.as_mut())
+            {
+                trading.selected = Some(trade_item_button.0);
+            }
+        }
+    }
+}
+
+type InteractedTradeRecipientButtonTuple = (Changed<Interaction>, With<TradeRecipientButton>);
+
+/// Handles offering the picked seed or cutting to another player.
+fn trade_recipient_button_system(
+    mut hot_seat: ResMut<HotSeat>,
+    mut lan: ResMut<Lan>,
+    planters: Res<Planters>,
+    mut seeds: ResMut<Seeds>,
+    interaction_query: Query<
+        (&Interaction, &TradeRecipientButton),
+        InteractedTradeRecipientButtonTuple,
+    >,
+) {
+    for (interaction, trade_recipient_button) in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            if hot_seat.is_trading() {
+                hot_seat.make_offer(trade_recipient_button.0);
+            } else {
+                lan.make_offer(trade_recipient_button.0, &planters, &mut seeds);
+            }
+        }
+    }
+}
+
+type InteractedTradeAcceptButtonTuple = (Changed<Interaction>, With<TradeAcceptButton>);
+
+/// Handles accepting an offer on the trading screen.
+fn trade_accept_button_system(
+    mut hot_seat: ResMut<HotSeat>,
+    mut lan: ResMut<Lan>,
+    mut seeds: ResMut<Seeds>,
+    mut status_text_query: Query<&mut Text, StatusTextFilter>,
+    interaction_query: Query<(&Interaction, &TradeAcceptButton), InteractedTradeAcceptButtonTuple>,
+) {
+    for (interaction, trade_accept_button) in interaction_query.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+
+        let accepted = if hot_seat.is_trading() {
+            hot_seat.accept_offer(trade_accept_button.0)
+        } else {
+            lan.answer_offer(trade_accept_button.0, true, &mut seeds)
+        };
+
+        if !accepted {
+            show_status_message(
+                &mut status_text_query,
+                "There's no room for any more seeds!".to_string(),
+            );
+        }
+    }
+}
+
+type InteractedTradeDeclineButtonTuple = (Changed<Interaction>, With<TradeDeclineButton>);
+
+/// Handles turning down an offer on the trading screen.
+fn trade_decline_button_system(
+    mut hot_seat: ResMut<HotSeat>,
+    mut lan: ResMut<Lan>,
+    mut seeds: ResMut<Seeds>,
+    interaction_query: Query<
+        (&Interaction, &TradeDeclineButton),
+        InteractedTradeDeclineButtonTuple,
+    >,
+) {
+    for (interaction, trade_decline_button) in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            if hot_seat.is_trading() {
+                hot_seat.decline_offer(trade_decline_button.0);
+            } else {
+                lan.answer_offer(trade_decline_button.0, false, &mut seeds);
+            }
+        }
+    }
+}
+
+type InteractedTradeDoneButtonTuple = (Changed<Interaction>, With<TradeDoneButton>);
+
+/// Handles the trading player finishing their turn, which ends trading once everyone's done.
+fn trade_done_button_system(
+    mut hot_seat: ResMut<HotSeat>,
+    mut lan: ResMut<Lan>,
+    mut planters: ResMut<Planters>,
+    mut seeds: ResMut<Seeds>,
+    mut season_log: ResMut<SeasonLog>,
+    mut status_text_query: Query<&mut Text, StatusTextFilter>,
+    interaction_query: Query<&Interaction, InteractedTradeDoneButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+
+        if hot_seat.is_trading() {
+            hot_seat.finish_trading_turn(&mut planters, &mut seeds, &mut season_log);
+
+            if let (false, Some(player)) = (hot_seat.is_trading(), hot_seat.current_player) {
+                show_status_message(
+                    &mut status_text_query,
+                    format!("Player {}'s turn", player + 1),
+                );
+            }
+        } else {
+            lan.finish_trading();
+        }
+    }
+}
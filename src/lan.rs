@@ -0,0 +1,1702 @@
+use std::{
+    io::{self, Read, Write},
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+    time::Duration,
+};
+
+use crate::*;
+
+pub const DEFAULT_LAN_PORT: u16 = 7878;
+// bumped whenever the messages change, so games that can't understand each other don't try to play together
+const PROTOCOL_VERSION: u32 = 1;
+
+// how long to keep trying to reach the host before giving up
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct LanPlugin;
+
+impl Plugin for LanPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Lan {
+            role: LanRole::from_args(std::env::args().skip(1)),
+            ..default()
+        })
+        .insert_resource(LanConnection::default())
+        .add_startup_system(lan_connect_system)
+        .add_system(lan_system)
+        .add_system(
+            lan_turn_system
+                .with_run_criteria(is_set_up)
+                .after(GameSystem::Input)
+                .before(GameSystem::SeasonTransition),
+        )
+        .add_system_set(SystemSet::on_exit(GameState::Game).with_system(leave_lan_game_system));
+    }
+}
+
+/// How this game takes part in LAN play, which is picked on the command line.
+#[derive(Clone, PartialEq, Eq, Default)]
+pub enum LanRole {
+    #[default]
+    Off,
+    /// Hosting on the provided port, which means deciding when the seasons change and passing trades on, as well as playing.
+    Host(u16),
+    /// Joining the host at the provided address.
+    Join(String),
+}
+
+impl LanRole {
+    /// Reads the role from the command line arguments, which can be `--host [port]` or `--join <address>`.
+    fn from_args(mut args: impl Iterator<Item = String>) -> LanRole {
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--host" => {
+                    let port = args.next().and_then(|port| port.parse().ok());
+                    return LanRole::Host(port.unwrap_or(DEFAULT_LAN_PORT));
+                }
+                "--join" => {
+                    if let Some(address) = args.next() {
+                        // the port can be left off if the host is using the default one
+                        return if address.contains(':') {
+                            LanRole::Join(address)
+                        } else {
+                            LanRole::Join(format!("{address}:{DEFAULT_LAN_PORT}"))
+                        };
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        LanRole::Off
+    }
+}
+
+/// A message sent between a game and the host. Each one is sent as a line of text, as described in PROTOCOL.md.
+pub enum LanMessage {
+    /// Sent by a game when it connects, to check that it speaks the same protocol as the host.
+    Hello { version: u32 },
+    /// Sent by the host to tell a game which player it is.
+    Welcome { player: usize },
+    /// Sent by the host whenever the number of players waiting to play changes.
+    Lobby { players: usize },
+    /// Sent by the host when it won't let a game play, just before it stops listening to it.
+    Refused { reason: String },
+    /// Sent by the host to start a match, with the seed everyone's starting plants are grown from.
+    Start {
+        seed: u64,
+        sexed_flowers: bool,
+        players: usize,
+    },
+    /// Sent by a game when its player has finished their turn for the season.
+    Ready,
+    /// Sent by the host once every player has finished their turn, so everyone moves on to the next season and starts trading.
+    Advance,
+    /// Sent by a game to offer a seed to another player, with an ID the game picks for the offer.
+    Offer { id: u32, to: usize, seed: Seed },
+    /// Sent by the host to pass an offer on to the player it's for.
+    Offered { from: usize, id: u32, seed: Seed },
+    /// Sent by a game to accept or decline an offer it was passed.
+    Answer { from: usize, id: u32, accept: bool },
+    /// Sent by the host to tell a game whether its offer was accepted.
+    Answered { id: u32, accepted: bool },
+    /// Sent by a game when its player has finished trading.
+    DoneTrading,
+    /// Sent by the host once every player has finished trading. Offers that weren't answered have been declined.
+    TradingOver,
+    /// Sent by a game when its player has grown a smart enough plant.
+    Won { plant: Plant },
+    /// Sent by the host to everyone else when a player has won.
+    Winner { player: usize, plant: Plant },
+    /// Sent by a game when its player stops playing the match, whether they won, lost, or gave up.
+    Leave,
+}
+
+impl std::fmt::Display for LanMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LanMessage::Hello { version } => write!(f, "hello {version}"),
+            LanMessage::Welcome { player } => write!(f, "welcome {player}"),
+            LanMessage::Lobby { players } => write!(f, "lobby {players}"),
+            LanMessage::Refused { reason } => write!(f, "refused {reason}"),
+            LanMessage::Start {
+                seed,
+                sexed_flowers,
+                players,
+            } => write!(f, "start {seed} {sexed_flowers} {players}"),
+            LanMessage::Ready => write!(f, "ready"),
+            LanMessage::Advance => write!(f, "advance"),
+            LanMessage::Offer { id, to, seed } => write!(f, "offer {id} {to} {}", write_seed(seed)),
+            LanMessage::Offered { from, id, seed } => {
+                write!(f, "offered {from} {id} {}", write_seed(seed))
+            }
+            LanMessage::Answer { from, id, accept } => {
+                let answer = if *accept { "accept" } else { "decline" };
+                write!(f, "answer {from} {id} {answer}")
+            }
+            LanMessage::Answered { id, accepted } => {
+                let answer = if *accepted { "accepted" } else { "declined" };
+                write!(f, "answered {id} {answer}")
+            }
+            LanMessage::DoneTrading => write!(f, "done_trading"),
+            LanMessage::TradingOver => write!(f, "trading_over"),
+            LanMessage::Won { plant } => write!(f, "won {}", write_plant(plant)),
+            LanMessage::Winner { player, plant } => {
+                write!(f, "winner {player} {}", write_plant(plant))
+            }
+            LanMessage::Leave => write!(f, "leave"),
+        }
+    }
+}
+
+impl LanMessage {
+    /// Reads a message from a line of text, if it's a valid one.
+    fn parse(line: &str) -> Option<LanMessage> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let word = |index: usize| words.get(index).copied();
+
+        let message = match word(0)? {
+            "hello" => LanMessage::Hello {
+                version: word(1)?.parse().ok()?,
+            },
+            "welcome" => LanMessage::Welcome {
+                player: word(1)?.parse().ok()?,
+            },
+            "lobby" => LanMessage::Lobby {
+                players: word(1)?.parse().ok()?,
+            },
+            "refused" => LanMessage::Refused {
+                reason: words[1..].join(" "),
+            },
+            "start" => LanMessage::Start {
+                seed: word(1)?.parse().ok()?,
+                sexed_flowers: word(2)?.parse().ok()?,
+                players: word(3)?.parse().ok()?,
+            },
+            "ready" => LanMessage::Ready,
+            "advance" => LanMessage::Advance,
+            "offer" => LanMessage::Offer {
+                id: word(1)?.parse().ok()?,
+                to: word(2)?.parse().ok()?,
+                seed: read_seed(words.get(3..)?)?,
+            },
+            "offered" => LanMessage::Offered {
+                from: word(1)?.parse().ok()?,
+                id: word(2)?.parse().ok()?,
+                seed: read_seed(words.get(3..)?)?,
+            },
+            "answer" => LanMessage::Answer {
+                from: word(1)?.parse().ok()?,
+                id: word(2)?.parse().ok()?,
+                accept: match word(3)? {
+                    "accept" => true,
+                    "decline" => false,
+                    _ => return None,
+                },
+            },
+            "answered" => LanMessage::Answered {
+                id: word(1)?.parse().ok()?,
+                accepted: match word(2)? {
+                    "accepted" => true,
+                    "declined" => false,
+                    _ => return None,
+                },
+            },
+            "done_trading" => LanMessage::DoneTrading,
+            "trading_over" => LanMessage::TradingOver,
+            "won" => LanMessage::Won {
+                plant: read_plant(words.get(1..)?)?,
+            },
+            "winner" => LanMessage::Winner {
+                player: word(1)?.parse().ok()?,
+                plant: read_plant(words.get(2..)?)?,
+            },
+            "leave" => LanMessage::Leave,
+            _ => return None,
+        };
+
+        Some(message)
+    }
+}
+
+/// Writes a plant as its name, age, inbreeding penalty and genes, separated by spaces.
+pub fn write_plant(plant: &Plant) -> String {
+    format!(
+        "{} {} {} {}",
+        write_name(&plant.name),
+        plant.age,
+        plant.inbreeding_penalty,
+        write_genes(&plant.genes)
+    )
+}
+
+pub fn read_plant(words: &[&str]) -> Option<Plant> {
+    match words {
+        [name, age, inbreeding_penalty, genes] => Some(Plant {
+            name: read_name(name),
+            age: age.parse().ok()?,
+            inbreeding_penalty: inbreeding_penalty.parse().ok()?,
+            genes: read_genes(genes)?,
+        }),
+        _ => None,
+    }
+}
+
+/// Writes a seed as its parents' names, origin, inbreeding penalty, seasons left to grow and genes, separated by spaces.
+fn write_seed(seed: &Seed) -> String {
+    format!(
+        "{} {} {} {} {} {}",
+        write_name(&seed.parent_name_1),
+        write_name(&seed.parent_name_2),
+        write_seed_origin(seed.origin),
+        seed.inbreeding_penalty,
+        seed.seasons_to_grow,
+        write_genes(&seed.genes)
+    )
+}
+
+fn read_seed(words: &[&str]) -> Option<Seed> {
+    match words {
+        [parent_name_1, parent_name_2, origin, inbreeding_penalty, seasons_to_grow, genes] => {
+            Some(Seed {
+                parent_name_1: read_name(parent_name_1),
+                parent_name_2: read_name(parent_name_2),
+                origin: read_seed_origin(origin)?,
+                inbreeding_penalty: inbreeding_penalty.parse().ok()?,
+                seasons_to_grow: seasons_to_grow.parse().ok()?,
+                genes: read_genes(genes)?,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Writes a name as its syllables separated by dashes, so the name can be split up again the same way.
+fn write_name(name: &PlantName) -> String {
+    name.syllables().join("-")
+}
+
+fn read_name(word: &str) -> PlantName {
+    word.split('-').collect::<Vec<_>>().into()
+}
+
+/// Writes genes separated by commas. Only a gene's category is written, since everything else about it follows from that.
+fn write_genes(genes: &[Gene]) -> String {
+    genes
+        .iter()
+        .map(|gene| write_gene_category(&gene.category()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn read_genes(word: &str) -> Option<Vec<Gene>> {
+    word.split(',')
+        .map(|category| read_gene_category(category).map(Gene::new_with_category))
+        .collect()
+}
+
+fn write_gene_category(category: &GeneCategory) -> String {
+    match category {
+        GeneCategory::StemStyle(style) => format!(
+            "stem_style:{}",
+            match style {
+                StemStyle::Curvy => "curvy",
+                StemStyle::Loopy => "loopy",
+                StemStyle::Angular => "angular",
+                StemStyle::Wiggly => "wiggly",
+            }
+        ),
+        GeneCategory::StemColor(color) => format!(
+            "stem_color:{}",
+            match color {
+                StemColor::Brown => "brown",
+                StemColor::Green => "green",
+                StemColor::Blue => "blue",
+            }
+        ),
+        GeneCategory::FruitStyle(style) => format!(
+            "fruit_style:{}",
+            match style {
+                FruitStyle::Circle => "circle",
+                FruitStyle::Square => "square",
+                FruitStyle::Triangle => "triangle",
+            }
+        ),
+        GeneCategory::FruitColor(color) => format!(
+            "fruit_color:{}",
+            match color {
+                FruitColor::Red => "red",
+                FruitColor::Purple => "purple",
+                FruitColor::Yellow => "yellow",
+            }
+        ),
+        GeneCategory::Lifespan(lifespan) => format!(
+            "lifespan:{}",
+            match lifespan {
+                Lifespan::Annual => "annual",
+                Lifespan::Perennial => "perennial",
+            }
+        ),
+        GeneCategory::Intellect(intellect) => format!(
+            "intellect:{}:{}",
+            intellect.locus,
+            match intellect.allele {
+                IntellectAllele::Low => "low",
+                IntellectAllele::High => "high",
+            }
+        ),
+        GeneCategory::FlowerType(flower_type) => format!(
+            "flower_type:{}",
+            match flower_type {
+                FlowerType::Male => "male",
+                FlowerType::Female => "female",
+                FlowerType::Hermaphrodite => "hermaphrodite",
+            }
+        ),
+    }
+}
+
+fn read_gene_category(word: &str) -> Option<GeneCategory> {
+    let parts = word.split(':').collect::<Vec<_>>();
+
+    let category = match parts.as_slice() {
+        ["stem_style", style] => GeneCategory::StemStyle(match *style {
+            "curvy" => StemStyle::Curvy,
+            "loopy" => StemStyle::Loopy,
+            "angular" => StemStyle::Angular,
+            "wiggly" => StemStyle::Wiggly,
+            _ => return None,
+        }),
+        ["stem_color", color] => GeneCategory::StemColor(match *color {
+            "brown" => StemColor::Brown,
+            "green" => StemColor::Green,
+            "blue" => StemColor::Blue,
+            _ => return None,
+        }),
+        ["fruit_style", style] => GeneCategory::FruitStyle(match *style {
+            "circle" => FruitStyle::Circle,
+            "square" => FruitStyle::Square,
+            "triangle" => FruitStyle::Triangle,
+            _ => return None,
+        }),
+        ["fruit_color", color] => GeneCategory::FruitColor(match *color {
+            "red" => FruitColor::Red,
+            "purple" => FruitColor::Purple,
+            "yellow" => FruitColor::Yellow,
+            _ => return None,
+        }),
+        ["lifespan", lifespan] => GeneCategory::Lifespan(match *lifespan {
+            "annual" => Lifespan::Annual,
+            "perennial" => Lifespan::Perennial,
+            _ => return None,
+        }),
+        ["intellect", locus, allele] => {
+            let locus = locus.parse::<u8>().ok()?;
+            if locus >= NUM_INTELLECT_LOCI {
+                return None;
+            }
+
+            GeneCategory::Intellect(IntellectGene {
+                locus,
+                allele: match *allele {
+                    "low" => IntellectAllele::Low,
+                    "high" => IntellectAllele::High,
+                    _ => return None,
+                },
+            })
+        }
+        ["flower_type", flower_type] => GeneCategory::FlowerType(match *flower_type {
+            "male" => FlowerType::Male,
+            "female" => FlowerType::Female,
+            "hermaphrodite" => FlowerType::Hermaphrodite,
+            _ => return None,
+        }),
+        _ => return None,
+    };
+
+    Some(category)
+}
+
+/// Writes how a seed was made. This is kept apart from how it's shown to the player, so saved files and other games can still read it if that changes.
+pub fn write_seed_origin(origin: SeedOrigin) -> &'static str {
+    match origin {
+        SeedOrigin::Spliced => "spliced",
+        SeedOrigin::SelfPollinated => "self-pollinated",
+        SeedOrigin::Cutting => "cutting",
+    }
+}
+
+pub fn read_seed_origin(word: &str) -> Option<SeedOrigin> {
+    match word {
+        "spliced" => Some(SeedOrigin::Spliced),
+        "self-pollinated" => Some(SeedOrigin::SelfPollinated),
+        "cutting" => Some(SeedOrigin::Cutting),
+        _ => None,
+    }
+}
+
+/// A connection to another game that messages can be sent and received over without waiting.
+struct Connection {
+    stream: TcpStream,
+    /// Anything that's been received that isn't a whole line yet.
+    incoming: Vec<u8>,
+    /// Anything that hasn't been sent yet because the connection was busy.
+    outgoing: Vec<u8>,
+    closed: bool,
+}
+
+impl Connection {
+    fn new(stream: TcpStream) -> io::Result<Connection> {
+        stream.set_nonblocking(true)?;
+        stream.set_nodelay(true)?;
+
+        Ok(Connection {
+            stream,
+            incoming: Vec::new(),
+            outgoing: Vec::new(),
+            closed: false,
+        })
+    }
+
+    fn send(&mut self, message: &LanMessage) {
+        self.outgoing
+            .extend_from_slice(format!("{message}\n").as_bytes());
+    }
+
+    /// Sends as much as can be sent right now, and returns any messages that have arrived.
+    /// If the other game has gone away, the connection is marked as closed.
+    fn poll(&mut self) -> Vec<LanMessage> {
+        if self.closed {
+            return Vec::new();
+        }
+
+        while !self.outgoing.is_empty() {
+            match self.stream.write(&self.outgoing) {
+                Ok(0) => {
+                    self.closed = true;
+                    break;
+                }
+                Ok(sent) => {
+                    self.outgoing.drain(..sent);
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => (),
+                Err(_) => {
+                    self.closed = true;
+                    break;
+                }
+            }
+        }
+
+        let mut buffer = [0; 4096];
+        while !self.closed {
+            match self.stream.read(&mut buffer) {
+                Ok(0) => self.closed = true,
+                Ok(received) => self.incoming.extend_from_slice(&buffer[..received]),
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => (),
+                Err(_) => self.closed = true,
+            }
+        }
+
+        // anything that arrived before the connection closed is still worth reading
+        let mut messages = Vec::new();
+        while let Some(end) = self.incoming.iter().position(|&byte| byte == b'\n') {
+            let line = self.incoming.drain(..=end).collect::<Vec<_>>();
+            let line = String::from_utf8_lossy(&line);
+            match LanMessage::parse(&line) {
+                Some(message) => messages.push(message),
+                None if !line.trim().is_empty() => warn!("Skipping unreadable LAN message: {line}"),
+                None => (),
+            }
+        }
+
+        messages
+    }
+}
+
+/// A game connected to the host.
+struct HostedPlayer {
+    connection: Connection,
+    /// Whether the game has said hello, so it's been given a player number.
+    joined: bool,
+    /// Whether the player is still playing the current match.
+    in_match: bool,
+    /// Whether the player has finished their turn for the season.
+    ready: bool,
+    /// Whether the player has finished trading.
+    done_trading: bool,
+}
+
+/// The host's side of LAN play, which decides when the seasons change and passes offers between players.
+/// The host's own game connects to it just like everyone else's.
+struct LanHost {
+    listener: TcpListener,
+    players: Vec<HostedPlayer>,
+    in_match: bool,
+    trading: bool,
+    /// Offers that haven't been answered yet, as the player who made it, their ID for it, and the player it's for.
+    offers: Vec<(usize, u32, usize)>,
+    has_winner: bool,
+}
+
+impl LanHost {
+    fn new(port: u16) -> io::Result<LanHost> {
+        let listener = TcpListener::bind(("0.0.0.0", port))?;
+        listener.set_nonblocking(true)?;
+
+        Ok(LanHost {
+            listener,
+            players: Vec::new(),
+            in_match: false,
+            trading: false,
+            offers: Vec::new(),
+            has_winner: false,
+        })
+    }
+
+    /// Lets in any games that have connected, and deals with whatever they've sent.
+    fn poll(&mut self) {
+        loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => match Connection::new(stream) {
+                    Ok(connection) => self.players.push(HostedPlayer {
+                        connection,
+                        joined: false,
+                        in_match: false,
+                        ready: false,
+                        done_trading: false,
+                    }),
+                    Err(e) => warn!("Unable to set up LAN connection: {e}"),
+                },
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) => {
+                    warn!("Unable to accept LAN connection: {e}");
+                    break;
+                }
+            }
+        }
+
+        for player in 0..self.players.len() {
+            let was_closed = self.players[player].connection.closed;
+            for message in self.players[player].connection.poll() {
+                self.handle_message(player, message);
+            }
+
+            if !was_closed && self.players[player].connection.closed {
+                self.leave_match(player);
+                self.players[player].joined = false;
+                self.send_lobby();
+            }
+        }
+
+        self.check_progress();
+    }
+
+    fn handle_message(&mut self, player: usize, message: LanMessage) {
+        match message {
+            LanMessage::Hello { version } => {
+                if version != PROTOCOL_VERSION {
+                    self.refuse(
+                        player,
+                        "the host is running a different version of the game",
+                    );
+                } else if self.in_match {
+                    self.refuse(player, "a match has already started");
+                } else {
+                    self.players[player].joined = true;
+                    self.send(player, LanMessage::Welcome { player });
+                    self.send_lobby();
+                }
+            }
+            LanMessage::Ready => {
+                if self.players[player].in_match && !self.trading {
+                    self.players[player].ready = true;
+                }
+            }
+            LanMessage::Offer { id, to, seed } => {
+                let recipient_in_match = self.players.get(to).map_or(false, |p| p.in_match);
+                if self.trading && to != player && recipient_in_match {
+                    self.offers.push((player, id, to));
+                    self.send(
+                        to,
+                        LanMessage::Offered {
+                            from: player,
+                            id,
+                            seed,
+                        },
+                    );
+                } else {
+                    self.send(
+                        player,
+                        LanMessage::Answered {
+                            id,
+                            accepted: false,
+                        },
+                    );
+                }
+            }
+            LanMessage::Answer { from, id, accept } => {
+                if let Some(index) = self
+                    .offers
+                    .iter()
+                    .position(|&offer| offer == (from, id, player))
+                {
+                    self.offers.remove(index);
+                    self.send(
+                        from,
+                        LanMessage::Answered {
+                            id,
+                            accepted: accept,
+                        },
+                    );
+                }
+            }
+            LanMessage::DoneTrading => self.players[player].done_trading = true,
+            LanMessage::Won { plant } => {
+                // if more than one player wins in the same season, the first one to say so is the winner
+                if self.players[player].in_match && !self.has_winner {
+                    self.has_winner = true;
+                    for other_player in 0..self.players.len() {
+                        if other_player != player && self.players[other_player].in_match {
+                            self.send(
+                                other_player,
+                                LanMessage::Winner {
+                                    player,
+                                    plant: plant.clone(),
+                                },
+                            );
+                        }
+                    }
+                }
+            }
+            LanMessage::Leave => self.leave_match(player),
+            _ => warn!("Ignoring LAN message that only the host should send: {message}"),
+        }
+    }
+
+    fn send(&mut self, player: usize, message: LanMessage) {
+        if let Some(player) = self.players.get_mut(player) {
+            player.connection.send(&message);
+        }
+    }
+
+    fn send_lobby(&mut self) {
+        if self.in_match {
+            return;
+        }
+
+        let players = self.players.iter().filter(|player| player.joined).count();
+        for player in 0..self.players.len() {
+            if self.players[player].joined {
+                self.send(player, LanMessage::Lobby { players });
+            }
+        }
+    }
+
+    /// Tells a game why it can't play, then stops listening to it.
+    fn refuse(&mut self, player: usize, reason: &str) {
+        self.send(
+            player,
+            LanMessage::Refused {
+                reason: reason.to_string(),
+            },
+        );
+        let connection = &mut self.players[player].connection;
+        connection.poll();
+        connection.closed = true;
+    }
+
+    /// Takes a player out of the current match, declining any offers they haven't answered.
+    fn leave_match(&mut self, player: usize) {
+        self.players[player].in_match = false;
+        self.players[player].ready = false;
+
+        let (declined, offers): (Vec<_>, Vec<_>) = std::mem::take(&mut self.offers)
+            .into_iter()
+            .partition(|&(_, _, to)| to == player);
+        self.offers = offers;
+        for (from, id, _) in declined {
+            self.send(
+                from,
+                LanMessage::Answered {
+                    id,
+                    accepted: false,
+                },
+            );
+        }
+
+        // once everyone's left, a new match can be started
+        if !self.players.iter().any(|player| player.in_match) {
+            self.in_match = false;
+            self.trading = false;
+            self.send_lobby();
+        }
+    }
+
+    /// Starts a match with everyone who's joined, if there's anyone to play against.
+    /// Returns whether the match was started.
+    fn start_match(&mut self, seed: u64, sexed_flowers: bool) -> bool {
+        if self.in_match {
+            return false;
+        }
+
+        self.players
+            .retain(|player| player.joined && !player.connection.closed);
+        if self.players.len() < 2 {
+            return false;
+        }
+
+        self.in_match = true;
+        self.trading = false;
+        self.offers.clear();
+        self.has_winner = false;
+
+        let players = self.players.len();
+        for player in 0..players {
+            let hosted_player = &mut self.players[player];
+            hosted_player.in_match = true;
+            hosted_player.ready = false;
+            hosted_player.done_trading = false;
+
+            // players who left the lobby have been dropped, so everyone gets their number again
+            self.send(player, LanMessage::Welcome { player });
+            self.send(
+                player,
+                LanMessage::Start {
+                    seed,
+                    sexed_flowers,
+                    players,
+                },
+            );
+        }
+
+        true
+    }
+
+    /// Moves on to the next season once everyone's finished their turn, and ends trading once everyone's finished trading.
+    fn check_progress(&mut self) {
+        if !self.in_match {
+            return;
+        }
+
+        let in_match = (0..self.players.len())
+            .filter(|&player| self.players[player].in_match)
+            .collect::<Vec<_>>();
+
+        if self.trading {
+            if in_match
+                .iter()
+                .all(|&player| self.players[player].done_trading)
+            {
+                for (from, id, _) in std::mem::take(&mut self.offers) {
+                    self.send(
+                        from,
+                        LanMessage::Answered {
+                            id,
+                            accepted: false,
+                        },
+                    );
+                }
+
+                self.trading = false;
+                for &player in &in_match {
+                    self.send(player, LanMessage::TradingOver);
+                }
+            }
+        } else if !in_match.is_empty() && in_match.iter().all(|&player| self.players[player].ready)
+        {
+            self.trading = true;
+            for &player in &in_match {
+                self.players[player].ready = false;
+                self.players[player].done_trading = false;
+                self.send(player, LanMessage::Advance);
+            }
+        }
+    }
+}
+
+/// The network side of LAN play, which is kept apart from `Lan` since it changes every frame.
+#[derive(Default)]
+pub struct LanConnection {
+    host: Option<LanHost>,
+    connection: Option<Connection>,
+    /// The connection to the host while it's being made, which happens in the background so the game doesn't freeze.
+    connecting: Option<Receiver<io::Result<TcpStream>>>,
+}
+
+impl LanConnection {
+    /// Starts a match with everyone who's joined, if this game is the host and there's anyone to play against.
+    /// Returns whether the match was started.
+    pub fn start_match(&mut self, seed: u64, sexed_flowers: bool) -> bool {
+        match &mut self.host {
+            Some(host) => host.start_match(seed, sexed_flowers),
+            None => false,
+        }
+    }
+}
+
+/// A seed offered to this player by another player.
+pub struct IncomingOffer {
+    pub from: usize,
+    pub id: u32,
+    pub seed: Seed,
+}
+
+/// A seed this player has offered to another player, which they haven't answered yet.
+pub struct OutgoingOffer {
+    pub id: u32,
+    pub to: usize,
+    pub seed: Seed,
+    /// Whether the seed came out of the seed tray, so it should go back there if the offer is declined.
+    pub from_seed_tray: bool,
+}
+
+/// The trading between seasons in a LAN match.
+pub struct LanTrading {
+    /// What the player has picked to offer, if anything.
+    pub selected: Option<TradeSelection>,
+    /// Whether the player has finished trading, though they can still answer offers until everyone else has.
+    pub done: bool,
+}
+
+/// A LAN match, where every player has their own farm but the host decides when the seasons change.
+pub struct LanMatch {
+    pub seed: u64,
+    pub sexed_flowers: bool,
+    pub num_players: usize,
+    /// Whether the game for the match has been set up, so restarting starts a single player game instead.
+    pub started: bool,
+    /// Whether the player has stopped playing the match.
+    pub left: bool,
+    /// Whether the player has finished their turn and is waiting for everyone else to finish theirs.
+    pub waiting: bool,
+    /// Whether the host has said the season can change.
+    pub can_advance: bool,
+    pub trading: Option<LanTrading>,
+    pub incoming_offers: Vec<IncomingOffer>,
+    pub outgoing_offers: Vec<OutgoingOffer>,
+    next_offer_id: u32,
+    /// The player who won, if it was someone else.
+    pub winner: Option<usize>,
+}
+
+/// Everything about LAN play that the rest of the game needs to know.
+#[derive(Default)]
+pub struct Lan {
+    pub role: LanRole,
+    pub connected: bool,
+    /// Which player this game is, once the host has said.
+    pub player: Option<usize>,
+    /// How many players are waiting to play, including this one.
+    pub players_in_lobby: usize,
+    /// Why there's no connection to the host, if there's been a problem.
+    pub problem: Option<String>,
+    /// The current or latest LAN match, which is cleared when a single player game starts.
+    pub lan_match: Option<LanMatch>,
+    /// Messages waiting to be sent to the host.
+    outbox: Vec<LanMessage>,
+}
+
+impl Lan {
+    /// Whether the player is in a LAN match that's being played.
+    pub fn is_playing(&self) -> bool {
+        self.connected
+            && self
+                .lan_match
+                .as_ref()
+                .map_or(false, |lan_match| lan_match.started && !lan_match.left)
+    }
+
+    /// Gets the LAN match the player is playing, if there is one, along with the messages waiting to be sent to the host.
+    fn playing_match(&mut self) -> Option<(&mut LanMatch, &mut Vec<LanMessage>)> {
+        if !self.is_playing() {
+            return None;
+        }
+
+        let outbox = &mut self.outbox;
+        self.lan_match.as_mut().map(|lan_match| (lan_match, outbox))
+    }
+
+    pub fn is_trading(&self) -> bool {
+        self.is_playing()
+            && self
+                .lan_match
+                .as_ref()
+                .map_or(false, |lan_match| lan_match.trading.is_some())
+    }
+
+    /// Whether the player has finished their turn and is waiting for the others, or trading, so they can't do anything with their plants.
+    pub fn is_between_turns(&self) -> bool {
+        self.is_playing()
+            && self.lan_match.as_ref().map_or(false, |lan_match| {
+                lan_match.waiting || lan_match.trading.is_some()
+            })
+    }
+
+    /// Tells the host the player has finished their turn, if they haven't already.
+    pub fn finish_turn(&mut self) {
+        if let Some((lan_match, outbox)) = self.playing_match() {
+            if !lan_match.waiting {
+                lan_match.waiting = true;
+                outbox.push(LanMessage::Ready);
+            }
+        }
+    }
+
+    /// Lets the player start trading, now that the season has changed.
+    pub fn start_trading(&mut self) {
+        if let Some((lan_match, _)) = self.playing_match() {
+            lan_match.trading = Some(LanTrading {
+                selected: None,
+                done: false,
+            });
+        }
+    }
+
+    /// Offers whatever the player has picked to the provided player.
+    pub fn make_offer(&mut self, to: usize, planters: &Planters, seeds: &mut Seeds) {
+        let (lan_match, outbox) = match self.playing_match() {
+            Some(playing_match) => playing_match,
+            None => return,
+        };
+        let selection = match lan_match
+            .trading
+            .as_mut()
+            .and_then(|trading| trading.selected.take())
+        {
+            Some(selection) => selection,
+            None => return,
+        };
+
+        if let Some((seed, from_seed_tray)) = take_trade_selection(selection, planters, seeds) {
+            let id = lan_match.next_offer_id;
+            lan_match.next_offer_id += 1;
+            lan_match.outgoing_offers.push(OutgoingOffer {
+                id,
+                to,
+                seed: seed.clone(),
+                from_seed_tray,
+            });
+            outbox.push(LanMessage::Offer { id, to, seed });
+        }
+    }
+
+    /// Accepts or declines the offer to the player with the provided index.
+    /// An offer can only be accepted if there's room for the seed, and returns whether it was.
+    pub fn answer_offer(&mut self, index: usize, accept: bool, seeds: &mut Seeds) -> bool {
+        let (lan_match, outbox) = match self.playing_match() {
+            Some(playing_match) => playing_match,
+            None => return false,
+        };
+        if index >= lan_match.incoming_offers.len() {
+            return false;
+        }
+        if accept && seeds.0.len() >= NUM_SEED_SPACES {
+            return false;
+        }
+
+        let offer = lan_match.incoming_offers.remove(index);
+        outbox.push(LanMessage::Answer {
+            from: offer.from,
+            id: offer.id,
+            accept,
+        });
+        if accept {
+            seeds.0.push(offer.seed);
+        }
+
+        accept
+    }
+
+    /// Tells the host the player has finished trading.
+    pub fn finish_trading(&mut self) {
+        if let Some((lan_match, outbox)) = self.playing_match() {
+            if let Some(trading) = &mut lan_match.trading {
+                if !trading.done {
+                    trading.done = true;
+                    outbox.push(LanMessage::DoneTrading);
+                }
+            }
+        }
+    }
+
+    /// Tells the host the player has grown a smart enough plant.
+    pub fn send_win(&mut self, plant: &Plant) {
+        if self.is_playing() {
+            self.outbox.push(LanMessage::Won {
+                plant: plant.clone(),
+            });
+        }
+    }
+
+    /// Tells the host the player has stopped playing the match.
+    fn leave(&mut self) {
+        if let Some((lan_match, outbox)) = self.playing_match() {
+            lan_match.left = true;
+            outbox.push(LanMessage::Leave);
+        }
+    }
+
+    /// Deals with a message from the host.
+    /// Returns whether it started a new match.
+    fn handle_message(&mut self, message: LanMessage, seeds: &mut Seeds) -> bool {
+        match message {
+            LanMessage::Welcome { player } => self.player = Some(player),
+            LanMessage::Lobby { players } => self.players_in_lobby = players,
+            LanMessage::Refused { reason } => {
+                self.connected = false;
+                self.problem = Some(format!("The host refused to let this game join: {reason}"));
+            }
+            LanMessage::Start {
+                seed,
+                sexed_flowers,
+                players,
+            } => {
+                self.lan_match = Some(LanMatch {
+                    seed,
+                    sexed_flowers,
+                    num_players: players,
+                    started: false,
+                    left: false,
+                    waiting: false,
+                    can_advance: false,
+                    trading: None,
+                    incoming_offers: Vec::new(),
+                    outgoing_offers: Vec::new(),
+                    next_offer_id: 0,
+                    winner: None,
+                });
+                return true;
+            }
+            LanMessage::Advance => {
+                if let Some((lan_match, _)) = self.playing_match() {
+                    lan_match.waiting = false;
+                    lan_match.can_advance = true;
+                }
+            }
+            LanMessage::Offered { from, id, seed } => {
+                if let Some((lan_match, _)) = self.playing_match() {
+                    lan_match
+                        .incoming_offers
+                        .push(IncomingOffer { from, id, seed });
+                }
+            }
+            LanMessage::Answered { id, accepted } => {
+                if let Some(lan_match) = self.lan_match.as_mut() {
+                    if let Some(index) = lan_match
+                        .outgoing_offers
+                        .iter()
+                        .position(|offer| offer.id == id)
+                    {
+                        let offer = lan_match.outgoing_offers.remove(index);
+
+                        // if the seed tray has filled up since, the seed is lost
+                        let can_return = !lan_match.left && seeds.0.len() < NUM_SEED_SPACES;
+                        if !accepted && offer.from_seed_tray && can_return {
+                            seeds.0.push(offer.seed);
+                        }
+                    }
+                }
+            }
+            LanMessage::TradingOver => {
+                if let Some((lan_match, _)) = self.playing_match() {
+                    lan_match.trading = None;
+                    lan_match.incoming_offers.clear();
+                }
+            }
+            LanMessage::Winner { player, .. } => {
+                if let Some((lan_match, _)) = self.playing_match() {
+                    lan_match.winner = Some(player);
+                }
+            }
+            _ => warn!("Ignoring LAN message that only games should send: {message}"),
+        }
+
+        false
+    }
+}
+
+/// Starts connecting to the host, after starting to host if this game is the host.
+fn lan_connect_system(mut lan: ResMut<Lan>, mut lan_connection: ResMut<LanConnection>) {
+    let address = match lan.role.clone() {
+        LanRole::Off => return,
+        LanRole::Host(port) => match LanHost::new(port) {
+            Ok(host) => {
+                lan_connection.host = Some(host);
+                format!("127.0.0.1:{port}")
+            }
+            Err(e) => {
+                lan.problem = Some(format!("Unable to host on port {port}: {e}"));
+                return;
+            }
+        },
+        LanRole::Join(address) => address,
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = connect(&address)
+            .map_err(|e| io::Error::new(e.kind(), format!("Unable to connect to {address}: {e}")));
+        // if the game has closed in the meantime, there's no one to tell
+        let _ = sender.send(result);
+    });
+    lan_connection.connecting = Some(receiver);
+}
+
+/// Connects to the provided address, trying everything it resolves to until one works or they've all timed out.
+fn connect(address: &str) -> io::Result<TcpStream> {
+    let mut last_error = io::Error::new(io::ErrorKind::NotFound, "no addresses found");
+    for socket_address in address.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&socket_address, CONNECT_TIMEOUT) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_error = e,
+        }
+    }
+
+    Err(last_error)
+}
+
+/// Finishes connecting to the host once the connection has been made, or explains why it couldn't be.
+fn finish_connecting(lan: &mut Lan, lan_connection: &mut LanConnection) {
+    let result = match lan_connection.connecting.as_ref().map(Receiver::try_recv) {
+        Some(Ok(result)) => result,
+        Some(Err(TryRecvError::Empty)) | None => return,
+        Some(Err(TryRecvError::Disconnected)) => Err(io::Error::new(
+            io::ErrorKind::Other,
+            "Unable to connect to the host",
+        )),
+    };
+    lan_connection.connecting = None;
+
+    match result.and_then(Connection::new) {
+        Ok(mut connection) => {
+            connection.send(&LanMessage::Hello {
+                version: PROTOCOL_VERSION,
+            });
+            lan_connection.connection = Some(connection);
+            lan.connected = true;
+        }
+        Err(e) => lan.problem = Some(e.to_string()),
+    }
+}
+
+/// Sends and receives LAN messages, and starts a LAN match when the host says to.
+fn lan_system(
+    mut lan: ResMut<Lan>,
+    mut lan_connection: ResMut<LanConnection>,
+    mut seeds: ResMut<Seeds>,
+    mut sexed_flowers: ResMut<SexedFlowers>,
+    mut versus_mode: ResMut<VersusMode>,
+    mut game_state: ResMut<State<GameState>>,
+) {
+    if lan_connection.connecting.is_some() {
+        finish_connecting(&mut lan, &mut lan_connection);
+    }
+
+    let LanConnection {
+        host, connection, ..
+    } = &mut *lan_connection;
+
+    if let Some(host) = host {
+        host.poll();
+    }
+
+    let connection = match connection {
+        Some(connection) => connection,
+        None => return,
+    };
+
+    // only touch the LAN state when something's happened, so other systems can tell when it changes
+    if !lan.outbox.is_empty() {
+        for message in lan.outbox.drain(..) {
+            connection.send(&message);
+        }
+    }
+
+    let messages = connection.poll();
+    for message in messages {
+        if lan.handle_message(message, &mut seeds) {
+            if let Some(lan_match) = &lan.lan_match {
+                sexed_flowers.0 = lan_match.sexed_flowers;
+            }
+            versus_mode.0 = false;
+
+            if *game_state.current() != GameState::GameLoading {
+                game_state.overwrite_set(GameState::GameLoading).unwrap();
+            }
+        }
+    }
+
+    if connection.closed && lan.connected {
+        lan.connected = false;
+        if lan.problem.is_none() {
+            lan.problem = Some("Lost connection to the host".to_string());
+        }
+    }
+
+    if !lan.connected {
+        lan_connection.connection = None;
+    }
+}
+
+/// Waits for everyone in a LAN match to finish their turn before the season changes, then changes it once the host says so.
+fn lan_turn_system(
+    mut season_transition: ResMut<SeasonTransition>,
+    mut lan: ResMut<Lan>,
+    mut keyboard_focus: ResMut<KeyboardFocus>,
+    mut status_text_query: Query<&mut Text, StatusTextFilter>,
+) {
+    if !lan.is_playing() {
+        return;
+    }
+
+    let can_advance = lan
+        .lan_match
+        .as_ref()
+        .map_or(false, |lan_match| lan_match.can_advance);
+    if can_advance {
+        if let Some(lan_match) = &mut lan.lan_match {
+            lan_match.can_advance = false;
+        }
+
+        keyboard_focus.clear_selection();
+        season_transition.start();
+        return;
+    }
+
+    if season_transition.is_starting() {
+        season_transition.cancel();
+
+        if !lan.is_between_turns() {
+            lan.finish_turn();
+            show_status_message(
+                &mut status_text_query,
+                "Waiting for the other players to finish their turn...".to_string(),
+            );
+        }
+    }
+}
+
+/// Leaves the LAN match when the game ends, whether the player won, lost, or gave up.
+fn leave_lan_game_system(mut lan: ResMut<Lan>) {
+    if lan.is_playing() {
+        lan.leave();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::Ipv4Addr, time::Instant};
+
+    use super::*;
+
+    // long enough that a slow machine doesn't fail the tests, since they only wait this long when a message is missing
+    const EXCHANGE_TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn all_gene_categories() -> Vec<GeneCategory> {
+        let mut categories = vec![
+            GeneCategory::StemStyle(StemStyle::Curvy),
+            GeneCategory::StemStyle(StemStyle::Loopy),
+            GeneCategory::StemStyle(StemStyle::Angular),
+            GeneCategory::StemStyle(StemStyle::Wiggly),
+            GeneCategory::StemColor(StemColor::Brown),
+            GeneCategory::StemColor(StemColor::Green),
+            GeneCategory::StemColor(StemColor::Blue),
+            GeneCategory::FruitStyle(FruitStyle::Circle),
+            GeneCategory::FruitStyle(FruitStyle::Square),
+            GeneCategory::FruitStyle(FruitStyle::Triangle),
+            GeneCategory::FruitColor(FruitColor::Red),
+            GeneCategory::FruitColor(FruitColor::Purple),
+            GeneCategory::FruitColor(FruitColor::Yellow),
+            GeneCategory::Lifespan(Lifespan::Annual),
+            GeneCategory::Lifespan(Lifespan::Perennial),
+            GeneCategory::FlowerType(FlowerType::Male),
+            GeneCategory::FlowerType(FlowerType::Female),
+            GeneCategory::FlowerType(FlowerType::Hermaphrodite),
+        ];
+        for locus in 0..NUM_INTELLECT_LOCI {
+            for allele in [IntellectAllele::Low, IntellectAllele::High] {
+                categories.push(GeneCategory::Intellect(IntellectGene { locus, allele }));
+            }
+        }
+
+        categories
+    }
+
+    fn test_plant() -> Plant {
+        Plant {
+            name: vec!["ro", "ber", "to"].into(),
+            genes: all_gene_categories()
+                .into_iter()
+                .map(Gene::new_with_category)
+                .collect(),
+            age: 2,
+            inbreeding_penalty: 3,
+        }
+    }
+
+    fn test_seed() -> Seed {
+        let plant = test_plant();
+        Seed {
+            parent_name_1: plant.name.clone(),
+            parent_name_2: vec!["vi", "o", "la"].into(),
+            genes: plant.genes,
+            origin: SeedOrigin::Spliced,
+            inbreeding_penalty: 1,
+            seasons_to_grow: 2,
+        }
+    }
+
+    fn all_messages() -> Vec<LanMessage> {
+        vec![
+            LanMessage::Hello {
+                version: PROTOCOL_VERSION,
+            },
+            LanMessage::Welcome { player: 1 },
+            LanMessage::Lobby { players: 3 },
+            LanMessage::Refused {
+                reason: "a match has already started".to_string(),
+            },
+            LanMessage::Start {
+                seed: u64::MAX,
+                sexed_flowers: true,
+                players: 2,
+            },
+            LanMessage::Ready,
+            LanMessage::Advance,
+            LanMessage::Offer {
+                id: 4,
+                to: 1,
+                seed: test_seed(),
+            },
+            LanMessage::Offered {
+                from: 0,
+                id: 4,
+                seed: test_seed(),
+            },
+            LanMessage::Answer {
+                from: 0,
+                id: 4,
+                accept: true,
+            },
+            LanMessage::Answer {
+                from: 0,
+                id: 5,
+                accept: false,
+            },
+            LanMessage::Answered {
+                id: 4,
+                accepted: true,
+            },
+            LanMessage::Answered {
+                id: 5,
+                accepted: false,
+            },
+            LanMessage::DoneTrading,
+            LanMessage::TradingOver,
+            LanMessage::Won {
+                plant: test_plant(),
+            },
+            LanMessage::Winner {
+                player: 1,
+                plant: test_plant(),
+            },
+            LanMessage::Leave,
+        ]
+    }
+
+    #[test]
+    fn messages_survive_being_written_and_read() {
+        for message in all_messages() {
+            let line = message.to_string();
+            let parsed = LanMessage::parse(&line)
+                .unwrap_or_else(|| panic!("unable to read back \"{line}\""));
+            assert_eq!(parsed.to_string(), line);
+        }
+    }
+
+    #[test]
+    fn unreadable_messages_are_skipped() {
+        for line in [
+            "",
+            "hello",
+            "hello one",
+            "start 1 maybe 2",
+            "offer 1 0",
+            "answer 0 1 maybe",
+            "answered 1 perhaps",
+            "won ro-ber-to 2 0 stem_style:spiky",
+            "dance",
+        ] {
+            assert!(LanMessage::parse(line).is_none(), "read \"{line}\"");
+        }
+    }
+
+    #[test]
+    fn genes_survive_being_written_and_read() {
+        for category in all_gene_categories() {
+            let word = write_gene_category(&category);
+            assert!(read_gene_category(&word) == Some(category), "{word}");
+        }
+
+        assert!(read_gene_category(&format!("intellect:{NUM_INTELLECT_LOCI}:high")).is_none());
+    }
+
+    #[test]
+    fn plants_and_seeds_survive_being_written_and_read() {
+        let plant = test_plant();
+        let written = write_plant(&plant);
+        let words = written.split(' ').collect::<Vec<_>>();
+        let read = read_plant(&words).unwrap();
+        assert_eq!(read.name.to_string(), plant.name.to_string());
+        assert_eq!(read.age, plant.age);
+        assert_eq!(read.inbreeding_penalty, plant.inbreeding_penalty);
+        assert!(read
+            .genes
+            .iter()
+            .map(Gene::category)
+            .eq(plant.genes.iter().map(Gene::category)));
+
+        for origin in [
+            SeedOrigin::Spliced,
+            SeedOrigin::SelfPollinated,
+            SeedOrigin::Cutting,
+        ] {
+            let seed = Seed {
+                origin,
+                ..test_seed()
+            };
+            let written = write_seed(&seed);
+            let words = written.split(' ').collect::<Vec<_>>();
+            let read = read_seed(&words).unwrap();
+            assert!(read.origin == seed.origin);
+            assert_eq!(
+                read.parent_name_1.to_string(),
+                seed.parent_name_1.to_string()
+            );
+            assert_eq!(read.seasons_to_grow, seed.seasons_to_grow);
+            assert_eq!(write_seed(&read), written);
+        }
+    }
+
+    #[test]
+    fn role_is_read_from_the_command_line() {
+        let role = |args: &[&str]| LanRole::from_args(args.iter().map(|arg| arg.to_string()));
+
+        assert!(role(&[]) == LanRole::Off);
+        assert!(role(&["--host"]) == LanRole::Host(DEFAULT_LAN_PORT));
+        assert!(role(&["--host", "9000"]) == LanRole::Host(9000));
+        assert!(role(&["--join", "10.0.0.2"]) == LanRole::Join("10.0.0.2:7878".to_string()));
+        assert!(role(&["--join", "10.0.0.2:9000"]) == LanRole::Join("10.0.0.2:9000".to_string()));
+    }
+
+    /// Starts a host on a free port, and connects the provided number of games to it.
+    fn start_host(num_games: usize) -> (LanHost, Vec<Connection>) {
+        let host = LanHost::new(0).unwrap();
+        let port = host.listener.local_addr().unwrap().port();
+        let games = (0..num_games)
+            .map(|_| {
+                Connection::new(TcpStream::connect((Ipv4Addr::LOCALHOST, port)).unwrap()).unwrap()
+            })
+            .collect();
+
+        (host, games)
+    }
+
+    /// Lets the host and the games send each other messages until `done` says everything expected has arrived,
+    /// and returns what each game received. Gives up after the provided time, so a lost message fails the test instead of hanging it.
+    fn exchange_until<F>(
+        host: &mut LanHost,
+        games: &mut [Connection],
+        timeout: Duration,
+        done: F,
+    ) -> Vec<Vec<String>>
+    where
+        F: Fn(&[Vec<String>]) -> bool,
+    {
+        let deadline = Instant::now() + timeout;
+        let mut received = vec![Vec::new(); games.len()];
+        while !done(&received) && Instant::now() < deadline {
+            host.poll();
+            for (game, received) in games.iter_mut().zip(&mut received) {
+                received.extend(game.poll().iter().map(LanMessage::to_string));
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+
+        received
+    }
+
+    /// Lets the host and the games send each other messages until each game has received at least the provided number of them.
+    fn exchange(
+        host: &mut LanHost,
+        games: &mut [Connection],
+        counts: &[usize],
+    ) -> Vec<Vec<String>> {
+        exchange_until(host, games, EXCHANGE_TIMEOUT, |received| {
+            received
+                .iter()
+                .zip(counts)
+                .all(|(received, count)| received.len() >= *count)
+        })
+    }
+
+    /// Lets the host and the games send each other messages for a moment, for checking that nothing is sent.
+    fn exchange_briefly(host: &mut LanHost, games: &mut [Connection]) -> Vec<Vec<String>> {
+        exchange_until(host, games, Duration::from_millis(100), |_| false)
+    }
+
+    /// Whether every game's latest message says the lobby has the provided number of players in it.
+    fn all_in_lobby(received: &[Vec<String>], num_players: usize) -> bool {
+        let lobby = format!("lobby {num_players}");
+        received
+            .iter()
+            .all(|received| received.last() == Some(&lobby))
+    }
+
+    #[test]
+    fn host_runs_a_match() {
+        let (mut host, mut games) = start_host(2);
+        let seed_text = write_seed(&test_seed());
+        let plant_text = write_plant(&test_plant());
+
+        for game in &mut games {
+            game.send(&LanMessage::Hello {
+                version: PROTOCOL_VERSION,
+            });
+        }
+        let received = exchange_until(&mut host, &mut games, EXCHANGE_TIMEOUT, |received| {
+            all_in_lobby(received, 2)
+        });
+        for (player, received) in received.iter().enumerate() {
+            assert!(
+                received.contains(&format!("welcome {player}")),
+                "{received:?}"
+            );
+            assert_eq!(received.last().unwrap(), "lobby 2");
+        }
+
+        assert!(host.start_match(42, true));
+        let received = exchange(&mut host, &mut games, &[2, 2]);
+        for (player, received) in received.iter().enumerate() {
+            assert_eq!(
+                received,
+                &[format!("welcome {player}"), "start 42 true 2".to_string()]
+            );
+        }
+
+        // the season only moves on once everyone's ready
+        games[0].send(&LanMessage::Ready);
+        let received = exchange_briefly(&mut host, &mut games);
+        assert!(received.iter().all(Vec::is_empty), "{received:?}");
+
+        games[1].send(&LanMessage::Ready);
+        let received = exchange(&mut host, &mut games, &[1, 1]);
+        assert!(received.iter().all(|received| received == &["advance"]));
+
+        // offers are passed on, and answers are passed back
+        games[0].send(&LanMessage::Offer {
+            id: 1,
+            to: 1,
+            seed: test_seed(),
+        });
+        games[1].send(&LanMessage::Offer {
+            id: 1,
+            to: 0,
+            seed: test_seed(),
+        });
+        let received = exchange(&mut host, &mut games, &[1, 1]);
+        assert_eq!(received[0], [format!("offered 1 1 {seed_text}")]);
+        assert_eq!(received[1], [format!("offered 0 1 {seed_text}")]);
+
+        games[1].send(&LanMessage::Answer {
+            from: 0,
+            id: 1,
+            accept: true,
+        });
+        games[0].send(&LanMessage::Answer {
+            from: 1,
+            id: 1,
+            accept: false,
+        });
+        let received = exchange(&mut host, &mut games, &[1, 1]);
+        assert_eq!(received[0], ["answered 1 accepted"]);
+        assert_eq!(received[1], ["answered 1 declined"]);
+
+        // offers that are still open when trading ends are declined
+        games[0].send(&LanMessage::Offer {
+            id: 2,
+            to: 1,
+            seed: test_seed(),
+        });
+        games[0].send(&LanMessage::DoneTrading);
+        games[1].send(&LanMessage::DoneTrading);
+        let received = exchange(&mut host, &mut games, &[2, 2]);
+        assert_eq!(received[0], ["answered 2 declined", "trading_over"]);
+        assert_eq!(
+            received[1],
+            [
+                format!("offered 0 2 {seed_text}"),
+                "trading_over".to_string()
+            ]
+        );
+
+        // the first player to win is the only winner
+        games[1].send(&LanMessage::Won {
+            plant: test_plant(),
+        });
+        games[0].send(&LanMessage::Won {
+            plant: test_plant(),
+        });
+        let received = exchange_until(&mut host, &mut games, EXCHANGE_TIMEOUT, |received| {
+            received.iter().any(|received| !received.is_empty())
+        });
+        let winners = received.concat();
+        assert_eq!(winners.len(), 1, "{winners:?}");
+        assert!(winners[0].ends_with(&plant_text));
+
+        for game in &mut games {
+            game.send(&LanMessage::Leave);
+        }
+        let received = exchange_until(&mut host, &mut games, EXCHANGE_TIMEOUT, |received| {
+            all_in_lobby(received, 2)
+        });
+        assert!(!host.in_match);
+        assert!(received.iter().all(|received| received == &["lobby 2"]));
+    }
+
+    #[test]
+    fn host_refuses_other_versions_and_late_arrivals() {
+        let (mut host, mut games) = start_host(3);
+
+        games[0].send(&LanMessage::Hello {
+            version: PROTOCOL_VERSION + 1,
+        });
+        games[1].send(&LanMessage::Hello {
+            version: PROTOCOL_VERSION,
+        });
+        games[2].send(&LanMessage::Hello {
+            version: PROTOCOL_VERSION,
+        });
+        let received = exchange_until(&mut host, &mut games, EXCHANGE_TIMEOUT, |received| {
+            !received[0].is_empty() && all_in_lobby(&received[1..], 2)
+        });
+        assert!(received[0][0].starts_with("refused "), "{received:?}");
+
+        assert!(host.start_match(7, false));
+        let port = host.listener.local_addr().unwrap().port();
+        games.push(
+            Connection::new(TcpStream::connect((Ipv4Addr::LOCALHOST, port)).unwrap()).unwrap(),
+        );
+        games[3].send(&LanMessage::Hello {
+            version: PROTOCOL_VERSION,
+        });
+        let received = exchange(&mut host, &mut games, &[0, 2, 2, 1]);
+        assert_eq!(received[1], ["welcome 0", "start 7 false 2"]);
+        assert_eq!(received[2], ["welcome 1", "start 7 false 2"]);
+        assert_eq!(received[3], ["refused a match has already started"]);
+    }
+}
@@ -4,12 +4,16 @@ pub struct LosePlugin;
 
 impl Plugin for LosePlugin {
     fn build(&self, app: &mut App) {
-        app.add_system_set(SystemSet::on_enter(GameState::Lose).with_system(lose_setup))
-            .add_system_set(
-                SystemSet::on_exit(GameState::Lose)
-                    .with_system(despawn_components_system::<LoseComponent>),
-            )
-            .add_system(restart_button_system);
+        app.add_system_set(
+            SystemSet::on_enter(GameState::Lose)
+                .with_system(lose_setup)
+                .with_system(play_lose_sound),
+        )
+        .add_system_set(
+            SystemSet::on_exit(GameState::Lose)
+                .with_system(despawn_components_system::<LoseComponent>),
+        )
+        .add_system(restart_button_system);
     }
 }
 
@@ -20,9 +24,30 @@ struct LoseComponent;
 struct RestartButton;
 
 /// Sets up the loss screen.
-fn lose_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn lose_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    rival: Res<Rival>,
+    lan: Res<Lan>,
+) {
     let title_font = asset_server.load(TITLE_FONT);
 
+    let lan_winner = lan
+        .lan_match
+        .as_ref()
+        .and_then(|lan_match| lan_match.winner);
+
+    let header_text = if let Some(player) = lan_winner {
+        format!(
+            "Player {} won the race.\n\nSecond place is not a smart plant.",
+            player + 1
+        )
+    } else if rival.smart_plant.is_some() {
+        "Your rival won the race.\n\nSecond place is not a smart plant.".to_string()
+    } else {
+        "All your plants have died.\n\nDead plants are not smart plants.".to_string()
+    };
+
     // header text
     commands
         .spawn_bundle(NodeBundle {
@@ -44,7 +69,7 @@ fn lose_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
         .with_children(|parent| {
             parent.spawn_bundle(
                 TextBundle::from_section(
-                    "All your plants have died.\n\nDead plants are not smart plants.",
+                    header_text,
                     TextStyle {
                         font: title_font.clone(),
                         font_size: 90.0,
@@ -117,3 +142,7 @@ fn restart_button_system(
         }
     }
 }
+
+fn play_lose_sound(sound_effects: SoundEffects) {
+    sound_effects.play(SoundEffect::Lose);
+}
@@ -1,6 +1,7 @@
 use bevy::{
     diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin},
     prelude::*,
+    window::WindowResizeConstraints,
 };
 use bevy_inspector_egui::{WorldInspectorParams, WorldInspectorPlugin};
 use bevy_kira_audio::prelude::*;
@@ -23,15 +24,41 @@ use lose::*;
 mod plant;
 use plant::*;
 
+mod settings;
+use settings::*;
+
+mod replay;
+use replay::*;
+
+mod hint;
+use hint::*;
+
+mod rival;
+use rival::*;
+
+mod hot_seat;
+use hot_seat::*;
+
+mod lan;
+use lan::*;
+
+mod records;
+use records::*;
+
 const DEV_MODE: bool = false;
 
 const MAIN_FONT: &str = "fonts/Quicksand-Medium.ttf";
 const TITLE_FONT: &str = "fonts/FredokaOne-Regular.ttf";
 const COMPUTER_FONT: &str = "fonts/VT323-Regular.ttf";
 
+// the size of the area that the game is laid out in, which gets scaled to fit the actual window
 const WINDOW_WIDTH: f32 = 1280.0;
 const WINDOW_HEIGHT: f32 = 720.0;
 
+// UI text is wrapped to 80% of the game area's width, so the window can't get narrower than that
+const MIN_WINDOW_WIDTH: f32 = WINDOW_WIDTH * 0.8;
+const MIN_WINDOW_HEIGHT: f32 = WINDOW_HEIGHT * 0.8;
+
 const NORMAL_BUTTON: Color = Color::rgb(0.25, 0.25, 0.25);
 const HOVERED_BUTTON: Color = Color::rgb(0.35, 0.35, 0.35);
 const PRESSED_BUTTON: Color = Color::rgb(0.35, 0.75, 0.35);
@@ -43,6 +70,9 @@ pub enum GameState {
     Game,
     Win,
     Lose,
+    Settings,
+    RecordsLoading,
+    Records,
 }
 
 fn main() {
@@ -52,6 +82,12 @@ fn main() {
             title: "Mr. Smartyplants".to_string(),
             width: WINDOW_WIDTH,
             height: WINDOW_HEIGHT,
+            resizable: true,
+            resize_constraints: WindowResizeConstraints {
+                min_width: MIN_WINDOW_WIDTH,
+                min_height: MIN_WINDOW_HEIGHT,
+                ..default()
+            },
             ..default()
         })
         .add_state(GameState::Menu)
@@ -61,11 +97,23 @@ fn main() {
         .add_plugin(GamePlugin)
         .add_plugin(WinPlugin)
         .add_plugin(LosePlugin)
+        .add_plugin(SettingsPlugin)
+        .add_plugin(ReplayPlugin)
+        .add_plugin(RivalPlugin)
+        .add_plugin(HotSeatPlugin)
+        .add_plugin(LanPlugin)
+        .add_plugin(RecordsPlugin)
         .add_system(button_color_system)
+        .add_system(camera_scale_system)
         .add_plugins(DefaultPlugins)
         .add_plugin(AudioPlugin);
 
-    if DEV_MODE {
+    let dev_mode = app
+        .world
+        .get_resource::<Settings>()
+        .map_or(DEV_MODE, |settings| settings.dev_mode);
+
+    if dev_mode {
         app.add_system(bevy::window::close_on_esc)
             .add_system(world_inspector_system)
             .add_plugin(LogDiagnosticsPlugin::default())
@@ -86,6 +134,39 @@ fn setup(mut commands: Commands) {
         .insert(MainCamera);
 }
 
+/// Scales and moves the camera so that the seeds and plants fit between the top and bottom bars, whatever size the window is.
+/// The bars and the rest of the UI stay the same size and are laid out across the window's width instead,
+/// and the cursor is worked out through the camera, so clicking still lands on the right things.
+fn camera_scale_system(
+    window_dimensions: Res<WindowDimensions>,
+    mut camera_query: Query<(&mut OrthographicProjection, &mut Transform), With<MainCamera>>,
+) {
+    let play_area_height = window_dimensions.0.y - TOP_BAR_HEIGHT - BOTTOM_BAR_HEIGHT;
+
+    // the window has no size while it's minimized
+    if window_dimensions.0.x <= 0.0 || play_area_height <= 0.0 {
+        return;
+    }
+
+    let scale = (WINDOW_WIDTH / window_dimensions.0.x)
+        .max((WINDOW_HEIGHT - TOP_BAR_HEIGHT - BOTTOM_BAR_HEIGHT) / play_area_height);
+
+    // the bars aren't the same height, so the space between them is a little off the middle of the window
+    let play_area_middle_y = (BOTTOM_BAR_HEIGHT - TOP_BAR_HEIGHT) / 2.0;
+    let camera_y = play_area_middle_y * (1.0 - scale);
+
+    for (mut projection, mut transform) in camera_query.iter_mut() {
+        // only touch the camera when the scale changes, so it isn't recalculated every frame
+        if (projection.scale - scale).abs() > f32::EPSILON {
+            projection.scale = scale;
+        }
+
+        if (transform.translation.y - camera_y).abs() > f32::EPSILON {
+            transform.translation.y = camera_y;
+        }
+    }
+}
+
 type InteractedButtonTuple = (Changed<Interaction>, With<Button>);
 
 /// Handles changing button colors when they're interacted with.
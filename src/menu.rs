@@ -11,7 +11,14 @@ impl Plugin for MenuPlugin {
                 SystemSet::on_exit(GameState::Menu)
                     .with_system(despawn_components_system::<MenuComponent>),
             )
-            .add_system(start_button_system);
+            .add_system(start_button_system)
+            .add_system(sexed_flowers_button_system)
+            .add_system(versus_button_system)
+            .add_system(players_button_system)
+            .add_system(lan_status_text_system)
+            .add_system(open_settings_button_system)
+            .add_system(watch_replay_button_system)
+            .add_system(open_records_button_system);
     }
 }
 
@@ -21,11 +28,74 @@ struct MenuComponent;
 #[derive(Component)]
 struct StartButton;
 
+#[derive(Component)]
+struct SexedFlowersButton;
+
+#[derive(Component)]
+struct SexedFlowersText;
+
+#[derive(Component)]
+struct VersusButton;
+
+#[derive(Component)]
+struct VersusText;
+
+#[derive(Component)]
+struct PlayersButton;
+
+#[derive(Component)]
+struct PlayersText;
+
+#[derive(Component)]
+struct LanStatusText;
+
+#[derive(Component)]
+struct OpenSettingsButton;
+
+#[derive(Component)]
+struct WatchReplayButton;
+
+#[derive(Component)]
+struct OpenRecordsButton;
+
 /// Sets up the main menu screen.
-fn menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn menu_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    sexed_flowers: Res<SexedFlowers>,
+    versus_mode: Res<VersusMode>,
+    hot_seat: Res<HotSeat>,
+    lan: Res<Lan>,
+) {
     let title_font = asset_server.load(TITLE_FONT);
     let main_font = asset_server.load(MAIN_FONT);
 
+    // LAN status text
+    if lan.role != LanRole::Off {
+        commands
+            .spawn_bundle(
+                TextBundle::from_section(
+                    get_lan_status_text(&lan),
+                    TextStyle {
+                        font: main_font.clone(),
+                        font_size: 25.0,
+                        color: Color::ORANGE,
+                    },
+                )
+                .with_style(Style {
+                    position_type: PositionType::Absolute,
+                    position: UiRect {
+                        top: Val::Px(10.0),
+                        left: Val::Px(10.0),
+                        ..default()
+                    },
+                    ..default()
+                }),
+            )
+            .insert(MenuComponent)
+            .insert(LanStatusText);
+    }
+
     // intro text
     commands
         .spawn_bundle(NodeBundle {
@@ -155,18 +225,372 @@ fn menu_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ));
                 });
         });
+
+    // options
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Px(70.0)),
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    bottom: Val::Px(0.0),
+                    ..default()
+                },
+                justify_content: JustifyContent::FlexEnd,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            color: UiColor(Color::NONE),
+            ..default()
+        })
+        .insert(MenuComponent)
+        .with_children(|parent| {
+            // watch replay button, for the last game that was played
+            if replay_exists() {
+                parent
+                    .spawn_bundle(ButtonBundle {
+                        style: Style {
+                            size: Size::new(Val::Px(250.0), Val::Px(50.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            margin: UiRect {
+                                right: Val::Px(10.0),
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        color: NORMAL_BUTTON.into(),
+                        ..default()
+                    })
+                    .insert(WatchReplayButton)
+                    .with_children(|parent| {
+                        parent.spawn_bundle(TextBundle::from_section(
+                            "Watch last game",
+                            TextStyle {
+                                font: main_font.clone(),
+                                font_size: 30.0,
+                                color: Color::SEA_GREEN,
+                            },
+                        ));
+                    });
+            }
+
+            // records button
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(200.0), Val::Px(50.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect {
+                            right: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    color: NORMAL_BUTTON.into(),
+                    ..default()
+                })
+                .insert(OpenRecordsButton)
+                .with_children(|parent| {
+                    parent.spawn_bundle(TextBundle::from_section(
+                        "Records",
+                        TextStyle {
+                            font: main_font.clone(),
+                            font_size: 30.0,
+                            color: Color::SEA_GREEN,
+                        },
+                    ));
+                });
+
+            // settings button
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(200.0), Val::Px(50.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect {
+                            right: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    color: NORMAL_BUTTON.into(),
+                    ..default()
+                })
+                .insert(OpenSettingsButton)
+                .with_children(|parent| {
+                    parent.spawn_bundle(TextBundle::from_section(
+                        "Settings",
+                        TextStyle {
+                            font: main_font.clone(),
+                            font_size: 30.0,
+                            color: Color::SEA_GREEN,
+                        },
+                    ));
+                });
+
+            // sexed flowers toggle
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(300.0), Val::Px(50.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect {
+                            right: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    color: NORMAL_BUTTON.into(),
+                    ..default()
+                })
+                .insert(SexedFlowersButton)
+                .with_children(|parent| {
+                    parent
+                        .spawn_bundle(TextBundle::from_section(
+                            get_sexed_flowers_text(&sexed_flowers),
+                            TextStyle {
+                                font: main_font.clone(),
+                                font_size: 30.0,
+                                color: Color::SEA_GREEN,
+                            },
+                        ))
+                        .insert(SexedFlowersText);
+                });
+
+            // versus mode toggle
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(200.0), Val::Px(50.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect {
+                            right: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    color: NORMAL_BUTTON.into(),
+                    ..default()
+                })
+                .insert(VersusButton)
+                .with_children(|parent| {
+                    parent
+                        .spawn_bundle(TextBundle::from_section(
+                            get_versus_text(&versus_mode),
+                            TextStyle {
+                                font: main_font.clone(),
+                                font_size: 30.0,
+                                color: Color::SEA_GREEN,
+                            },
+                        ))
+                        .insert(VersusText);
+                });
+
+            // number of players toggle
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(200.0), Val::Px(50.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect {
+                            right: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    color: NORMAL_BUTTON.into(),
+                    ..default()
+                })
+                .insert(PlayersButton)
+                .with_children(|parent| {
+                    parent
+                        .spawn_bundle(TextBundle::from_section(
+                            get_players_text(&hot_seat),
+                            TextStyle {
+                                font: main_font.clone(),
+                                font_size: 30.0,
+                                color: Color::SEA_GREEN,
+                            },
+                        ))
+                        .insert(PlayersText);
+                });
+        });
 }
 
 type InteractedStartButtonTuple = (Changed<Interaction>, With<StartButton>);
 
 /// Handles interactions with the start button.
+/// When hosting a LAN match that others have joined, this starts the match for everyone instead of a single player game.
 fn start_button_system(
     mut game_state: ResMut<State<GameState>>,
+    mut lan_connection: ResMut<LanConnection>,
+    sexed_flowers: Res<SexedFlowers>,
     interaction_query: Query<&Interaction, InteractedStartButtonTuple>,
 ) {
     for interaction in interaction_query.iter() {
-        if *interaction == Interaction::Clicked {
+        if *interaction == Interaction::Clicked
+            && !lan_connection.start_match(rand::random(), sexed_flowers.0)
+        {
             game_state.set(GameState::GameLoading).unwrap();
         }
     }
 }
+
+fn get_sexed_flowers_text(sexed_flowers: &SexedFlowers) -> String {
+    if sexed_flowers.0 {
+        "Flower sexes: on".to_string()
+    } else {
+        "Flower sexes: off".to_string()
+    }
+}
+
+type InteractedSexedFlowersButtonTuple = (Changed<Interaction>, With<SexedFlowersButton>);
+
+/// Handles interactions with the sexed flowers button.
+fn sexed_flowers_button_system(
+    mut sexed_flowers: ResMut<SexedFlowers>,
+    mut text_query: Query<&mut Text, With<SexedFlowersText>>,
+    interaction_query: Query<&Interaction, InteractedSexedFlowersButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            sexed_flowers.0 = !sexed_flowers.0;
+            for mut text in text_query.iter_mut() {
+                text.sections[0].value = get_sexed_flowers_text(&sexed_flowers);
+            }
+        }
+    }
+}
+
+fn get_versus_text(versus_mode: &VersusMode) -> String {
+    if versus_mode.0 {
+        "Versus: on".to_string()
+    } else {
+        "Versus: off".to_string()
+    }
+}
+
+type InteractedVersusButtonTuple = (Changed<Interaction>, With<VersusButton>);
+
+/// Handles interactions with the versus mode button.
+fn versus_button_system(
+    mut versus_mode: ResMut<VersusMode>,
+    mut text_query: Query<&mut Text, With<VersusText>>,
+    interaction_query: Query<&Interaction, InteractedVersusButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            versus_mode.0 = !versus_mode.0;
+            for mut text in text_query.iter_mut() {
+                text.sections[0].value = get_versus_text(&versus_mode);
+            }
+        }
+    }
+}
+
+fn get_players_text(hot_seat: &HotSeat) -> String {
+    format!("Players: {}", hot_seat.num_players)
+}
+
+type InteractedPlayersButtonTuple = (Changed<Interaction>, With<PlayersButton>);
+
+/// Handles interactions with the number of players button, cycling through the numbers of players that can take turns.
+fn players_button_system(
+    mut hot_seat: ResMut<HotSeat>,
+    mut text_query: Query<&mut Text, With<PlayersText>>,
+    interaction_query: Query<&Interaction, InteractedPlayersButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            hot_seat.num_players = hot_seat.num_players % MAX_PLAYERS + 1;
+            for mut text in text_query.iter_mut() {
+                text.sections[0].value = get_players_text(&hot_seat);
+            }
+        }
+    }
+}
+
+type InteractedOpenSettingsButtonTuple = (Changed<Interaction>, With<OpenSettingsButton>);
+
+/// Handles interactions with the settings button.
+fn open_settings_button_system(
+    mut game_state: ResMut<State<GameState>>,
+    interaction_query: Query<&Interaction, InteractedOpenSettingsButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            game_state.set(GameState::Settings).unwrap();
+        }
+    }
+}
+
+type InteractedWatchReplayButtonTuple = (Changed<Interaction>, With<WatchReplayButton>);
+
+/// Handles interactions with the watch replay button.
+fn watch_replay_button_system(
+    mut game_state: ResMut<State<GameState>>,
+    mut playback: ResMut<ReplayPlayback>,
+    interaction_query: Query<&Interaction, InteractedWatchReplayButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            match load_replay() {
+                Some(replay) => {
+                    playback.replay = Some(replay);
+                    game_state.set(GameState::GameLoading).unwrap();
+                }
+                None => warn!("Unable to load the replay"),
+            }
+        }
+    }
+}
+
+type InteractedOpenRecordsButtonTuple = (Changed<Interaction>, With<OpenRecordsButton>);
+
+/// Handles interactions with the records button.
+fn open_records_button_system(
+    mut game_state: ResMut<State<GameState>>,
+    interaction_query: Query<&Interaction, InteractedOpenRecordsButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            game_state.set(GameState::RecordsLoading).unwrap();
+        }
+    }
+}
+
+fn get_lan_status_text(lan: &Lan) -> String {
+    if let Some(problem) = &lan.problem {
+        return problem.clone();
+    }
+
+    match (&lan.role, lan.player) {
+        (LanRole::Host(port), _) => format!(
+            "Hosting a LAN match on port {port}, with {} players so far",
+            lan.players_in_lobby
+        ),
+        (_, Some(player)) => format!(
+            "Joined a LAN match as Player {}, with {} players so far. Waiting for the host...",
+            player + 1,
+            lan.players_in_lobby
+        ),
+        _ => "Connecting to the host...".to_string(),
+    }
+}
+
+/// Keeps the LAN status text up to date as players join and leave.
+fn lan_status_text_system(lan: Res<Lan>, mut text_query: Query<&mut Text, With<LanStatusText>>) {
+    if !lan.is_changed() {
+        return;
+    }
+
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = get_lan_status_text(&lan);
+    }
+}
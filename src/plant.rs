@@ -1,7 +1,60 @@
-use rand::{seq::SliceRandom, Rng};
+use std::{collections::HashMap, sync::Mutex};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
 
 const PEST_DESTRUCTION_THRESHOLD: i32 = 5;
 const PEST_DESTRUCTION_CHANCE: f32 = 0.18;
+const PERENNIAL_VIGOUR_LOSS_SEASONS: u32 = 3;
+const SELF_POLLINATION_INBREEDING_PENALTY: i32 = 2;
+const CUTTING_SEASONS_TO_GROW: u32 = 2;
+const INBREEDING_DEPRESSION_PENALTY: i32 = 1;
+pub const NUM_INTELLECT_LOCI: u8 = 3;
+
+/// The random number generator for everything that happens to plants and seeds.
+/// It's shared and seedable so that a game can be played again exactly the same way.
+static RNG: Mutex<Option<StdRng>> = Mutex::new(None);
+
+/// Starts the random number generator for plants and seeds over from the provided seed.
+pub fn seed_rng(seed: u64) {
+    *RNG.lock().unwrap() = Some(StdRng::seed_from_u64(seed));
+}
+
+/// Calls the provided function with the random number generator for plants and seeds.
+/// If it hasn't been seeded yet, it's seeded randomly.
+fn with_rng<T>(f: impl FnOnce(&mut StdRng) -> T) -> T {
+    let mut rng = RNG.lock().unwrap();
+    f(rng.get_or_insert_with(StdRng::from_entropy))
+}
+
+/// A chromosome holds loci in order, along with the chance of a crossover happening between each locus and the one before it.
+/// Every locus other than intellect should be on exactly one chromosome.
+struct Chromosome {
+    loci: &'static [(Locus, f32)],
+}
+
+const CHROMOSOMES: [Chromosome; 4] = [
+    Chromosome {
+        loci: &[(Locus::StemStyle, 0.0), (Locus::StemColor, 0.2)],
+    },
+    Chromosome {
+        loci: &[(Locus::FruitColor, 0.0), (Locus::FruitStyle, 0.1)],
+    },
+    Chromosome {
+        loci: &[(Locus::Lifespan, 0.0)],
+    },
+    Chromosome {
+        loci: &[(Locus::FlowerType, 0.0)],
+    },
+];
+
+/// Each intellect locus goes at the end of the chromosome with the same index, so no 2 intellect loci are linked to each other.
+/// These are the chances of a crossover happening between each one and the locus before it.
+const INTELLECT_CROSSOVER_CHANCES: [f32; NUM_INTELLECT_LOCI as usize] = [0.4, 0.3, 0.25];
+
+const _: () = assert!(
+    NUM_INTELLECT_LOCI as usize <= CHROMOSOMES.len(),
+    "every intellect locus needs a chromosome"
+);
 
 #[derive(Clone)]
 pub struct PlantName {
@@ -16,6 +69,12 @@ impl<T: std::fmt::Display> From<Vec<T>> for PlantName {
     }
 }
 
+impl PlantName {
+    pub fn syllables(&self) -> &[String] {
+        &self.syllables
+    }
+}
+
 impl std::fmt::Display for PlantName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", uppercase_first_letter(&self.syllables.concat()))
@@ -33,29 +92,30 @@ fn uppercase_first_letter(s: &str) -> String {
 /// Combines the provided names into a new name with syllables from both.
 pub fn combine_names(name_1: &PlantName, name_2: &PlantName) -> PlantName {
     let num_syllables = name_1.syllables.len().max(name_2.syllables.len());
-    let mut rng = rand::thread_rng();
     let mut syllables = Vec::new();
 
-    if let Some(syllable) = name_1.syllables.choose(&mut rng) {
-        syllables.push(syllable);
-    }
-
-    if let Some(syllable) = name_2.syllables.choose(&mut rng) {
-        syllables.push(syllable);
-    }
+    with_rng(|rng| {
+        if let Some(syllable) = name_1.syllables.choose(rng) {
+            syllables.push(syllable);
+        }
 
-    let all_syllables = name_1
-        .syllables
-        .iter()
-        .chain(name_2.syllables.iter())
-        .collect::<Vec<&String>>();
-    while syllables.len() < num_syllables {
-        if let Some(syllable) = all_syllables.choose(&mut rng) {
+        if let Some(syllable) = name_2.syllables.choose(rng) {
             syllables.push(syllable);
-        } else {
-            break;
         }
-    }
+
+        let all_syllables = name_1
+            .syllables
+            .iter()
+            .chain(name_2.syllables.iter())
+            .collect::<Vec<&String>>();
+        while syllables.len() < num_syllables {
+            if let Some(syllable) = all_syllables.choose(rng) {
+                syllables.push(syllable);
+            } else {
+                break;
+            }
+        }
+    });
 
     syllables.into()
 }
@@ -64,41 +124,174 @@ pub fn combine_names(name_1: &PlantName, name_2: &PlantName) -> PlantName {
 pub struct Plant {
     pub name: PlantName,
     pub genes: Vec<Gene>,
+    /// The number of seasons this plant has been alive for.
+    pub age: u32,
+    /// How much pest resistance this plant has lost from being inbred.
+    pub inbreeding_penalty: i32,
 }
 
+#[derive(Clone, Default)]
 pub struct Planters(pub Vec<Planter>);
 
+#[derive(Clone)]
 pub enum Planter {
     Plant(Plant),
-    DeadPlant(Plant),
+    DeadPlant(Plant, CauseOfDeath),
     Seed(Seed),
+    FailedSeed(Seed),
     Empty,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CauseOfDeath {
+    Pests,
+    OldAge,
+}
+
+impl std::fmt::Display for CauseOfDeath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CauseOfDeath::Pests => write!(f, "eaten by pests"),
+            CauseOfDeath::OldAge => write!(f, "old age"),
+        }
+    }
+}
+
+/// What happened in the planters when moving on to a new season.
+#[derive(Default)]
+pub struct SeasonReport {
+    /// The season that was moved on to.
+    pub season: u32,
+    /// The names of the plants that grew from seeds.
+    pub grown: Vec<PlantName>,
+    /// How many seeds failed to sprout.
+    pub failed_seeds: usize,
+    /// The names of the plants that died, and what killed them.
+    pub deaths: Vec<(PlantName, CauseOfDeath)>,
+    /// What the plants that are left are like, if there are any.
+    pub stats: Option<PhenotypeStats>,
+}
+
+/// A summary of the phenotypes of all the living plants.
+pub struct PhenotypeStats {
+    pub living_plants: usize,
+    pub best_intelligence: i32,
+    pub average_intelligence: f32,
+    pub average_pest_resistance: f32,
+}
+
 impl Planters {
-    /// Updates the planters for next season.
-    pub fn next_season(&mut self) {
-        // grow seeds
+    /// Ages the plants and grows the seeds for next season, returning a report of what happened.
+    /// The report doesn't include anything about pests, since they arrive later.
+    pub fn grow(&mut self) -> SeasonReport {
+        let mut report = SeasonReport::default();
+
+        // age plants
         self.0.iter_mut().for_each(|planter| {
-            if let Planter::Seed(seed) = planter {
-                *planter = Planter::Plant(seed.grow())
+            if let Planter::Plant(plant) = planter {
+                plant.age += 1;
+                if plant.is_past_lifespan() {
+                    report
+                        .deaths
+                        .push((plant.name.clone(), CauseOfDeath::OldAge));
+                    *planter = Planter::DeadPlant(plant.clone(), CauseOfDeath::OldAge)
+                }
             }
         });
 
-        // unleash the pests
+        // grow seeds
         self.0.iter_mut().for_each(|planter| {
-            if let Planter::Plant(plant) = planter {
-                let phenotype = plant.get_phenotype();
-                if phenotype.pest_resistance < PEST_DESTRUCTION_THRESHOLD {
-                    let difference = PEST_DESTRUCTION_THRESHOLD - phenotype.pest_resistance;
-                    let destruction_chance = difference as f32 * PEST_DESTRUCTION_CHANCE;
-                    if rand::thread_rng().gen::<f32>() <= destruction_chance {
-                        *planter = Planter::DeadPlant(plant.clone())
+            if let Planter::Seed(seed) = planter {
+                if seed.seasons_to_grow > 1 {
+                    seed.seasons_to_grow -= 1;
+                } else {
+                    *planter = match seed.grow() {
+                        Some(plant) => {
+                            report.grown.push(plant.name.clone());
+                            Planter::Plant(plant)
+                        }
+                        None => {
+                            report.failed_seeds += 1;
+                            Planter::FailedSeed(seed.clone())
+                        }
                     }
                 }
             }
         });
+
+        report
+    }
+
+    /// Decides which plants the pests are going to eat this season, returning the indices of their planters.
+    pub fn choose_pest_victims(&self) -> Vec<usize> {
+        with_rng(|rng| {
+            self.0
+                .iter()
+                .enumerate()
+                .filter_map(|(index, planter)| {
+                    if let Planter::Plant(plant) = planter {
+                        let phenotype = plant.get_phenotype();
+                        if phenotype.pest_resistance < PEST_DESTRUCTION_THRESHOLD {
+                            let destruction_chance =
+                                get_pest_destruction_chance(phenotype.pest_resistance);
+                            if rng.gen::<f32>() <= destruction_chance {
+                                return Some(index);
+                            }
+                        }
+                    }
+
+                    None
+                })
+                .collect()
+        })
+    }
+
+    /// Kills the plants in the planters with the provided indices, since they've been eaten by pests.
+    /// Their deaths are added to the provided report.
+    pub fn unleash_pests(&mut self, victims: &[usize], report: &mut SeasonReport) {
+        for &index in victims {
+            if let Some(planter) = self.0.get_mut(index) {
+                if let Planter::Plant(plant) = planter {
+                    report
+                        .deaths
+                        .push((plant.name.clone(), CauseOfDeath::Pests));
+                    *planter = Planter::DeadPlant(plant.clone(), CauseOfDeath::Pests)
+                }
+            }
+        }
     }
+
+    /// Summarizes the phenotypes of the living plants, if there are any.
+    pub fn get_phenotype_stats(&self) -> Option<PhenotypeStats> {
+        let phenotypes = self
+            .0
+            .iter()
+            .filter_map(|planter| match planter {
+                Planter::Plant(plant) => Some(plant.get_phenotype()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let best_intelligence = phenotypes
+            .iter()
+            .map(|phenotype| phenotype.intelligence)
+            .max()?;
+        let total_intelligence: i32 = phenotypes.iter().map(|p| p.intelligence).sum();
+        let total_pest_resistance: i32 = phenotypes.iter().map(|p| p.pest_resistance).sum();
+
+        Some(PhenotypeStats {
+            living_plants: phenotypes.len(),
+            best_intelligence,
+            average_intelligence: total_intelligence as f32 / phenotypes.len() as f32,
+            average_pest_resistance: total_pest_resistance as f32 / phenotypes.len() as f32,
+        })
+    }
+}
+
+/// Gets the chance that a plant with the provided pest resistance gets eaten by pests in a season.
+pub fn get_pest_destruction_chance(pest_resistance: i32) -> f32 {
+    let difference = (PEST_DESTRUCTION_THRESHOLD - pest_resistance).max(0);
+    (difference as f32 * PEST_DESTRUCTION_CHANCE).min(1.0)
 }
 
 pub struct Phenotype {
@@ -106,96 +299,337 @@ pub struct Phenotype {
     pub stem_color: StemColor,
     pub fruit_style: FruitStyle,
     pub fruit_color: FruitColor,
+    pub lifespan: Lifespan,
+    pub flower_type: FlowerType,
     pub intelligence: i32,
     pub pest_resistance: i32,
+    pub gene_interactions: Vec<&'static GeneInteraction>,
+}
+
+/// An interaction between genes that happens when they're all expressed at the same time.
+pub struct GeneInteraction {
+    pub name: &'static str,
+    pub description: &'static str,
+    required_genes: &'static [GeneCategory],
+    effect: InteractionEffect,
+}
+
+#[derive(PartialEq, Eq)]
+enum InteractionEffect {
+    /// Changes the plant's stats
+    Bonus {
+        intelligence: i32,
+        pest_resistance: i32,
+    },
+    /// Prevents the gene at a locus from being expressed, so the plant acts like it doesn't have one there
+    Suppress(Locus),
+}
+
+impl GeneInteraction {
+    /// Determines whether this interaction happens when the provided genes are expressed.
+    fn applies_to(&self, expressed_categories: &[GeneCategory]) -> bool {
+        self.required_genes
+            .iter()
+            .all(|category| expressed_categories.contains(category))
+    }
 }
 
+pub const GENE_INTERACTIONS: [GeneInteraction; 3] = [
+    GeneInteraction {
+        name: "Blue Genius",
+        description: "Blue stems and triangle fruit together give +2 intelligence",
+        required_genes: &[
+            GeneCategory::StemColor(StemColor::Blue),
+            GeneCategory::FruitStyle(FruitStyle::Triangle),
+        ],
+        effect: InteractionEffect::Bonus {
+            intelligence: 2,
+            pest_resistance: 0,
+        },
+    },
+    GeneInteraction {
+        name: "Bark Shade",
+        description: "Brown stems stop fruit from getting their color",
+        required_genes: &[GeneCategory::StemColor(StemColor::Brown)],
+        effect: InteractionEffect::Suppress(Locus::FruitColor),
+    },
+    GeneInteraction {
+        name: "Tangled Defense",
+        description: "Loopy stems and purple fruit together give +2 pest resistance",
+        required_genes: &[
+            GeneCategory::StemStyle(StemStyle::Loopy),
+            GeneCategory::FruitColor(FruitColor::Purple),
+        ],
+        effect: InteractionEffect::Bonus {
+            intelligence: 0,
+            pest_resistance: 2,
+        },
+    },
+];
+
 impl Plant {
     pub fn get_phenotype(&self) -> Phenotype {
         let mut intelligence = 0;
         let mut pest_resistance = 0;
 
-        //
-        // stem style
-        //
-        let default_stem_style_gene = Gene::new_with_stem_style(StemStyle::Curvy);
-        let stem_style_gene = get_expressed_gene(
-            &self.genes,
-            |gene| matches!(gene.category, GeneCategory::StemStyle(_)),
-            &default_stem_style_gene,
-        );
+        let expressed_genes = [
+            Locus::StemStyle,
+            Locus::StemColor,
+            Locus::FruitStyle,
+            Locus::FruitColor,
+            Locus::Lifespan,
+            Locus::FlowerType,
+        ]
+        .map(|locus| {
+            let default_gene = locus.default_gene();
+            get_expressed_gene(
+                &self.genes,
+                |gene| gene.category.locus() == locus,
+                &default_gene,
+            )
+            .clone()
+        });
+
+        // interactions are based on the genes that would be expressed if there weren't any interactions
+        let expressed_categories = expressed_genes
+            .iter()
+            .map(|gene| gene.category)
+            .collect::<Vec<GeneCategory>>();
+        let gene_interactions = GENE_INTERACTIONS
+            .iter()
+            .filter(|interaction| interaction.applies_to(&expressed_categories))
+            .collect::<Vec<&'static GeneInteraction>>();
+
+        let [stem_style_gene, stem_color_gene, fruit_style_gene, fruit_color_gene, lifespan_gene, flower_type_gene] =
+            expressed_genes.map(|gene| {
+                let locus = gene.category.locus();
+                let is_suppressed = gene_interactions
+                    .iter()
+                    .any(|interaction| interaction.effect == InteractionEffect::Suppress(locus));
+                if is_suppressed {
+                    locus.default_gene()
+                } else {
+                    gene
+                }
+            });
 
         let stem_style = match stem_style_gene.category {
             GeneCategory::StemStyle(x) => x,
             _ => unreachable!("stem style gene isn't in the stem style category somehow"),
         };
 
-        intelligence += stem_style_gene.intelligence_effect;
-        pest_resistance += stem_style_gene.pest_resistance_effect;
-
-        //
-        // stem color
-        //
-        let default_stem_color_gene = Gene::new_with_stem_color(StemColor::Green);
-        let stem_color_gene = get_expressed_gene(
-            &self.genes,
-            |gene| matches!(gene.category, GeneCategory::StemColor(_)),
-            &default_stem_color_gene,
-        );
-
         let stem_color = match stem_color_gene.category {
             GeneCategory::StemColor(x) => x,
             _ => unreachable!("stem color gene isn't in the stem color category somehow"),
         };
 
-        intelligence += stem_color_gene.intelligence_effect;
-        pest_resistance += stem_color_gene.pest_resistance_effect;
-
-        //
-        // fruit style
-        //
-        let default_fruit_style_gene = Gene::new_with_fruit_style(FruitStyle::Circle);
-        let fruit_style_gene = get_expressed_gene(
-            &self.genes,
-            |gene| matches!(gene.category, GeneCategory::FruitStyle(_)),
-            &default_fruit_style_gene,
-        );
-
         let fruit_style = match fruit_style_gene.category {
             GeneCategory::FruitStyle(x) => x,
             _ => unreachable!("fruit style gene isn't in the fruit style category somehow"),
         };
 
-        intelligence += fruit_style_gene.intelligence_effect;
-        pest_resistance += fruit_style_gene.pest_resistance_effect;
-
-        //
-        // fruit color
-        //
-        let default_fruit_color_gene = Gene::new_with_fruit_color(FruitColor::Red);
-        let fruit_color_gene = get_expressed_gene(
-            &self.genes,
-            |gene| matches!(gene.category, GeneCategory::FruitColor(_)),
-            &default_fruit_color_gene,
-        );
-
         let fruit_color = match fruit_color_gene.category {
             GeneCategory::FruitColor(x) => x,
             _ => unreachable!("fruit color gene isn't in the fruit color category somehow"),
         };
 
-        intelligence += fruit_color_gene.intelligence_effect;
-        pest_resistance += fruit_color_gene.pest_resistance_effect;
+        let lifespan = match lifespan_gene.category {
+            GeneCategory::Lifespan(x) => x,
+            _ => unreachable!("lifespan gene isn't in the lifespan category somehow"),
+        };
+
+        let flower_type = match flower_type_gene.category {
+            GeneCategory::FlowerType(x) => x,
+            _ => unreachable!("flower type gene isn't in the flower type category somehow"),
+        };
+
+        for gene in [
+            &stem_style_gene,
+            &stem_color_gene,
+            &fruit_style_gene,
+            &fruit_color_gene,
+            &lifespan_gene,
+            &flower_type_gene,
+        ] {
+            intelligence += gene.intelligence_effect;
+            pest_resistance += gene.pest_resistance_effect;
+        }
+
+        for interaction in &gene_interactions {
+            if let InteractionEffect::Bonus {
+                intelligence: intelligence_bonus,
+                pest_resistance: pest_resistance_bonus,
+            } = interaction.effect
+            {
+                intelligence += intelligence_bonus;
+                pest_resistance += pest_resistance_bonus;
+            }
+        }
+
+        //
+        // intellect
+        //
+        // every intellect gene adds to the plant's intelligence, rather than one being expressed over the other
+        self.genes
+            .iter()
+            .filter(|gene| matches!(gene.category, GeneCategory::Intellect(_)))
+            .for_each(|gene| {
+                intelligence += gene.intelligence_effect;
+                pest_resistance += gene.pest_resistance_effect;
+            });
+
+        // perennials get weaker as they get older
+        if lifespan == Lifespan::Perennial {
+            pest_resistance -= (self.age / PERENNIAL_VIGOUR_LOSS_SEASONS) as i32;
+        }
+
+        pest_resistance -= self.inbreeding_penalty;
+
+        // plants without any genetic variation are more vulnerable
+        if count_heterozygous_traits(&self.genes) == 0 {
+            pest_resistance -= INBREEDING_DEPRESSION_PENALTY;
+        }
 
         Phenotype {
             stem_style,
             stem_color,
             fruit_style,
             fruit_color,
+            lifespan,
+            flower_type,
             intelligence,
             pest_resistance,
+            gene_interactions,
+        }
+    }
+
+    /// Determines whether this plant's pollen can be used to make a seed with the provided plant when flowers have sexes.
+    pub fn can_pollinate(&self, receiver: &Plant) -> bool {
+        self.get_phenotype().flower_type.has_pollen()
+            && receiver.get_phenotype().flower_type.has_pistil()
+    }
+
+    /// Determines whether this plant is unable to be spliced with other plants.
+    pub fn is_sterile(&self) -> bool {
+        has_gene_combination(&self.genes, &STERILE_GENE_COMBINATIONS)
+    }
+
+    /// Determines whether this plant has lived longer than its lifespan allows.
+    pub fn is_past_lifespan(&self) -> bool {
+        match self.get_phenotype().lifespan.max_age() {
+            Some(max_age) => self.age >= max_age,
+            None => false,
+        }
+    }
+}
+
+/// Combinations of genes that a plant can't survive having 2 copies of each of
+const LETHAL_GENE_COMBINATIONS: [&[GeneCategory]; 2] = [
+    &[
+        GeneCategory::StemColor(StemColor::Blue),
+        GeneCategory::FruitColor(FruitColor::Yellow),
+    ],
+    &[
+        GeneCategory::StemStyle(StemStyle::Angular),
+        GeneCategory::FruitStyle(FruitStyle::Triangle),
+    ],
+];
+
+/// Combinations of genes that make a plant sterile if it has 2 copies of each of them
+const STERILE_GENE_COMBINATIONS: [&[GeneCategory]; 1] = [&[
+    GeneCategory::StemStyle(StemStyle::Loopy),
+    GeneCategory::FruitColor(FruitColor::Yellow),
+]];
+
+/// Determines whether the provided genes have 2 copies of every gene in any of the provided combinations.
+fn has_gene_combination(genes: &[Gene], combinations: &[&[GeneCategory]]) -> bool {
+    combinations.iter().any(|combination| {
+        combination.iter().all(|category| {
+            genes
+                .iter()
+                .filter(|gene| gene.category == *category)
+                .count()
+                >= 2
+        })
+    })
+}
+
+/// Groups the provided genes by the trait they're for.
+fn group_by_trait(genes: &[Gene]) -> HashMap<Locus, Vec<GeneCategory>> {
+    let mut traits: HashMap<_, Vec<GeneCategory>> = HashMap::new();
+    for gene in genes {
+        traits
+            .entry(gene.category.locus())
+            .or_default()
+            .push(gene.category);
+    }
+
+    traits
+}
+
+fn count_heterozygous_traits(genes: &[Gene]) -> usize {
+    group_by_trait(genes)
+        .values()
+        .filter(|categories| categories.iter().any(|category| *category != categories[0]))
+        .count()
+}
+
+/// Gets the fraction of the provided genes' traits that have more than one version of their gene.
+pub fn get_heterozygosity(genes: &[Gene]) -> f32 {
+    let num_traits = group_by_trait(genes).len();
+    if num_traits == 0 {
+        return 0.0;
+    }
+
+    count_heterozygous_traits(genes) as f32 / num_traits as f32
+}
+
+/// Determines whether there's nothing left to grow, with no plants, no planted seeds, and no seeds to plant.
+pub fn is_out_of_plants(planters: &Planters, seeds: &Seeds) -> bool {
+    seeds.0.is_empty()
+        && !planters
+            .0
+            .iter()
+            .any(|planter| matches!(planter, Planter::Plant(_) | Planter::Seed(_)))
+}
+
+/// Gets how genetically diverse all the living plants and seeds are, from 0 (all identical) to 1.
+/// This is the chance that 2 genes for the same trait picked at random from the population are different, averaged over all the traits.
+pub fn get_genetic_diversity(planters: &Planters, seeds: &Seeds) -> f32 {
+    let planter_genes = planters.0.iter().filter_map(|planter| match planter {
+        Planter::Plant(plant) => Some(&plant.genes),
+        Planter::Seed(seed) => Some(&seed.genes),
+        Planter::DeadPlant(_, _) | Planter::FailedSeed(_) | Planter::Empty => None,
+    });
+    let seed_genes = seeds.0.iter().map(|seed| &seed.genes);
+
+    let mut gene_counts: HashMap<_, HashMap<GeneCategory, usize>> = HashMap::new();
+    for genes in planter_genes.chain(seed_genes) {
+        for gene in genes {
+            *gene_counts
+                .entry(gene.category.locus())
+                .or_default()
+                .entry(gene.category)
+                .or_default() += 1;
         }
     }
+
+    if gene_counts.is_empty() {
+        return 0.0;
+    }
+
+    let total_diversity = gene_counts
+        .values()
+        .map(|counts| {
+            let total = counts.values().sum::<usize>() as f32;
+            1.0 - counts
+                .values()
+                .map(|count| (*count as f32 / total).powi(2))
+                .sum::<f32>()
+        })
+        .sum::<f32>();
+
+    total_diversity / gene_counts.len() as f32
 }
 
 fn get_expressed_gene<'a, F>(
@@ -209,10 +643,16 @@ where
     let dominant_genes = get_matching_genes(genes, GeneDominance::Dominant, &category_filter);
     let recessive_genes = get_matching_genes(genes, GeneDominance::Recessive, &category_filter);
 
-    dominant_genes
-        .first()
-        .or_else(|| recessive_genes.first())
-        .unwrap_or(&default_gene)
+    choose_expressed_gene(dominant_genes)
+        .or_else(|| choose_expressed_gene(recessive_genes))
+        .unwrap_or(default_gene)
+}
+
+/// Picks which of the provided equally dominant genes is expressed.
+/// Ties go to whichever gene's category comes first in the order they're declared in,
+/// so the same genes always look the same whichever parent passed them down.
+fn choose_expressed_gene(candidates: Vec<&Gene>) -> Option<&Gene> {
+    candidates.into_iter().min_by_key(|gene| gene.category)
 }
 
 fn get_matching_genes<'a, F>(
@@ -231,110 +671,173 @@ where
 
 /// Splices together the genes of 2 plants
 pub fn splice_plants(plant_1: &Plant, plant_2: &Plant) -> Seed {
+    let genes = with_rng(|rng| splice_genes(&plant_1.genes, &plant_2.genes, rng));
+    new_seed(plant_1, plant_2, SeedOrigin::Spliced, genes)
+}
+
+/// Splices together the genes of a plant with itself, at the cost of some pest resistance
+pub fn self_pollinate(plant: &Plant) -> Seed {
+    let genes = with_rng(|rng| splice_genes(&plant.genes, &plant.genes, rng));
+    new_seed(plant, plant, SeedOrigin::SelfPollinated, genes)
+}
+
+/// Takes a cutting from a plant, which grows into an exact copy of it but takes longer to grow
+pub fn take_cutting(plant: &Plant) -> Seed {
+    new_seed(plant, plant, SeedOrigin::Cutting, plant.genes.clone())
+}
+
+/// Makes a seed from the provided plants the same way the provided origin would, using the provided random number generator.
+/// This doesn't touch the shared random number generator, so it can be used to predict what seeds might come out without changing the game.
+pub fn imagine_seed(
+    plant_1: &Plant,
+    plant_2: &Plant,
+    origin: SeedOrigin,
+    rng: &mut impl Rng,
+) -> Seed {
+    let genes = match origin {
+        SeedOrigin::Spliced | SeedOrigin::SelfPollinated => {
+            splice_genes(&plant_1.genes, &plant_2.genes, rng)
+        }
+        SeedOrigin::Cutting => plant_1.genes.clone(),
+    };
+
+    new_seed(plant_1, plant_2, origin, genes)
+}
+
+/// Makes a seed with the provided genes, with everything else set up the way seeds with the provided origin are.
+fn new_seed(plant_1: &Plant, plant_2: &Plant, origin: SeedOrigin, genes: Vec<Gene>) -> Seed {
+    let (inbreeding_penalty, seasons_to_grow) = match origin {
+        SeedOrigin::Spliced => (0, 1),
+        SeedOrigin::SelfPollinated => (
+            plant_1.inbreeding_penalty + SELF_POLLINATION_INBREEDING_PENALTY,
+            1,
+        ),
+        SeedOrigin::Cutting => (plant_1.inbreeding_penalty, CUTTING_SEASONS_TO_GROW),
+    };
+
     Seed {
         parent_name_1: plant_1.name.clone(),
         parent_name_2: plant_2.name.clone(),
-        genes: splice_genes(&plant_1.genes, &plant_2.genes),
-    }
-}
-
-fn splice_genes(genes_1: &[Gene], genes_2: &[Gene]) -> Vec<Gene> {
-    let mut genes = Vec::new();
-
-    // stem style
-    let default_stem_style_gene = Gene::new_with_stem_style(StemStyle::Curvy);
-    let stem_style_genes = get_spliced_genes(
-        genes_1,
-        genes_2,
-        |gene| matches!(gene.category, GeneCategory::StemStyle(_)),
-        default_stem_style_gene,
-    );
-    genes.extend(stem_style_genes);
-
-    // stem color
-    let default_stem_color_gene = Gene::new_with_stem_color(StemColor::Green);
-    let stem_color_genes = get_spliced_genes(
-        genes_1,
-        genes_2,
-        |gene| matches!(gene.category, GeneCategory::StemColor(_)),
-        default_stem_color_gene,
-    );
-    genes.extend(stem_color_genes);
-
-    // fruit style
-    let default_fruit_style_gene = Gene::new_with_fruit_style(FruitStyle::Circle);
-    let fruit_style_genes = get_spliced_genes(
-        genes_1,
-        genes_2,
-        |gene| matches!(gene.category, GeneCategory::FruitStyle(_)),
-        default_fruit_style_gene,
-    );
-    genes.extend(fruit_style_genes);
-
-    // fruit color
-    let default_fruit_color_gene = Gene::new_with_fruit_color(FruitColor::Red);
-    let fruit_color_genes = get_spliced_genes(
-        genes_1,
-        genes_2,
-        |gene| matches!(gene.category, GeneCategory::FruitColor(_)),
-        default_fruit_color_gene,
-    );
-    genes.extend(fruit_color_genes);
+        genes,
+        origin,
+        inbreeding_penalty,
+        seasons_to_grow,
+    }
+}
 
-    genes
+fn splice_genes(genes_1: &[Gene], genes_2: &[Gene], rng: &mut impl Rng) -> Vec<Gene> {
+    let gamete_1 = get_gamete(genes_1, rng);
+    let gamete_2 = get_gamete(genes_2, rng);
+
+    // keep the genes from each parent in the same order so linked genes stay together in future generations
+    gamete_1
+        .into_iter()
+        .zip(gamete_2)
+        .flat_map(|(gene_1, gene_2)| [gene_1, gene_2])
+        .collect()
 }
 
-fn get_spliced_genes<F>(
-    genes_1: &[Gene],
-    genes_2: &[Gene],
-    category_filter: F,
-    default_gene: Gene,
-) -> Vec<Gene>
-where
-    F: Fn(&Gene) -> bool,
-{
-    let found_genes_1 = genes_1
-        .iter()
-        .filter(|gene| category_filter(gene))
-        .collect::<Vec<&Gene>>();
-    let found_genes_2 = genes_2
-        .iter()
-        .filter(|gene| category_filter(gene))
-        .collect::<Vec<&Gene>>();
+/// Picks one gene at each locus to pass down from the provided genes.
+/// Genes on the same chromosome are passed down together, unless a crossover happens between them.
+fn get_gamete(genes: &[Gene], rng: &mut impl Rng) -> Vec<Gene> {
+    let mut gamete = Vec::new();
 
-    let mut rng = rand::thread_rng();
+    for (index, chromosome) in CHROMOSOMES.iter().enumerate() {
+        let intellect_locus = INTELLECT_CROSSOVER_CHANCES
+            .get(index)
+            .map(|crossover_chance| (Locus::Intellect(index as u8), *crossover_chance));
 
-    let gene_1 = found_genes_1
-        .choose(&mut rng)
-        .map(|gene| (*gene).clone())
-        .unwrap_or_else(|| default_gene.clone());
-    let gene_2 = found_genes_2
-        .choose(&mut rng)
-        .map(|gene| (*gene).clone())
-        .unwrap_or(default_gene);
+        let mut strand = rng.gen_range(0..2);
+        for (locus, crossover_chance) in chromosome.loci.iter().copied().chain(intellect_locus) {
+            if rng.gen::<f32>() < crossover_chance {
+                strand = 1 - strand;
+            }
 
-    let mut genes = vec![gene_1, gene_2];
-    genes.shuffle(&mut rng);
+            let locus_genes = genes
+                .iter()
+                .filter(|gene| gene.category.locus() == locus)
+                .collect::<Vec<&Gene>>();
+            let gene = locus_genes
+                .get(strand)
+                .or_else(|| locus_genes.first())
+                .map(|gene| (*gene).clone())
+                .unwrap_or_else(|| locus.default_gene());
+
+            gamete.push(gene);
+        }
+    }
 
-    genes
+    gamete
 }
 
+#[derive(Clone)]
 pub struct Seed {
     pub parent_name_1: PlantName,
     pub parent_name_2: PlantName,
     pub genes: Vec<Gene>,
+    pub origin: SeedOrigin,
+    pub inbreeding_penalty: i32,
+    /// The number of seasons left until this seed grows into a plant.
+    pub seasons_to_grow: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SeedOrigin {
+    Spliced,
+    SelfPollinated,
+    Cutting,
+}
+
+impl std::fmt::Display for SeedOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SeedOrigin::Spliced => write!(f, "spliced"),
+            SeedOrigin::SelfPollinated => write!(f, "self-pollinated"),
+            SeedOrigin::Cutting => write!(f, "cutting"),
+        }
+    }
 }
 
 impl Seed {
-    /// Turns this seed into a plant.
-    pub fn grow(&self) -> Plant {
+    /// Determines whether this seed is able to grow into a plant.
+    pub fn is_viable(&self) -> bool {
+        !has_gene_combination(&self.genes, &LETHAL_GENE_COMBINATIONS)
+    }
+
+    /// Gets the phenotype of the plant this seed would grow into.
+    pub fn get_phenotype(&self) -> Phenotype {
         Plant {
-            name: combine_names(&self.parent_name_1, &self.parent_name_2),
+            name: self.parent_name_1.clone(),
             genes: self.genes.clone(),
+            age: 0,
+            inbreeding_penalty: self.inbreeding_penalty,
         }
+        .get_phenotype()
+    }
+
+    /// Turns this seed into a plant, if it's able to grow.
+    pub fn grow(&self) -> Option<Plant> {
+        if !self.is_viable() {
+            return None;
+        }
+
+        let name = match self.origin {
+            SeedOrigin::Cutting => self.parent_name_1.clone(),
+            SeedOrigin::Spliced | SeedOrigin::SelfPollinated => {
+                combine_names(&self.parent_name_1, &self.parent_name_2)
+            }
+        };
+
+        Some(Plant {
+            name,
+            genes: self.genes.clone(),
+            age: 0,
+            inbreeding_penalty: self.inbreeding_penalty,
+        })
     }
 }
 
+#[derive(Default)]
 pub struct Seeds(pub Vec<Seed>);
 
 #[derive(Clone)]
@@ -349,17 +852,67 @@ pub struct Gene {
 pub enum GeneDominance {
     Dominant,
     Recessive,
+    /// Both genes are expressed, and their effects add together
+    Additive,
 }
 
-#[derive(Hash, PartialEq, Eq, Clone, Copy)]
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub enum GeneCategory {
     StemStyle(StemStyle),
     StemColor(StemColor),
     FruitStyle(FruitStyle),
     FruitColor(FruitColor),
+    Lifespan(Lifespan),
+    Intellect(IntellectGene),
+    FlowerType(FlowerType),
 }
 
+/// Identifies where a gene is, so that genes at the same locus are different versions of the same thing.
 #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+pub enum Locus {
+    StemStyle,
+    StemColor,
+    FruitStyle,
+    FruitColor,
+    Lifespan,
+    Intellect(u8),
+    FlowerType,
+}
+
+impl Locus {
+    /// Gets the gene to use for this locus if a plant doesn't have one.
+    fn default_gene(&self) -> Gene {
+        match self {
+            Locus::StemStyle => Gene::new_with_stem_style(StemStyle::Curvy),
+            Locus::StemColor => Gene::new_with_stem_color(StemColor::Green),
+            Locus::FruitStyle => Gene::new_with_fruit_style(FruitStyle::Circle),
+            Locus::FruitColor => Gene::new_with_fruit_color(FruitColor::Red),
+            Locus::Lifespan => Gene::new_with_lifespan(Lifespan::Perennial),
+            Locus::Intellect(locus) => Gene::new_with_intellect(IntellectGene {
+                locus: *locus,
+                allele: IntellectAllele::Low,
+            }),
+            Locus::FlowerType => Gene::new_with_flower_type(FlowerType::Hermaphrodite),
+        }
+    }
+}
+
+impl GeneCategory {
+    /// Gets the locus genes in this category are found at.
+    pub fn locus(&self) -> Locus {
+        match self {
+            GeneCategory::StemStyle(_) => Locus::StemStyle,
+            GeneCategory::StemColor(_) => Locus::StemColor,
+            GeneCategory::FruitStyle(_) => Locus::FruitStyle,
+            GeneCategory::FruitColor(_) => Locus::FruitColor,
+            GeneCategory::Lifespan(_) => Locus::Lifespan,
+            GeneCategory::Intellect(x) => Locus::Intellect(x.locus),
+            GeneCategory::FlowerType(_) => Locus::FlowerType,
+        }
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub enum StemStyle {
     Curvy,
     Loopy,
@@ -367,34 +920,107 @@ pub enum StemStyle {
     Wiggly,
 }
 
-#[derive(Hash, PartialEq, Eq, Clone, Copy)]
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub enum StemColor {
     Brown,
     Green,
     Blue,
 }
 
-#[derive(Hash, PartialEq, Eq, Clone, Copy)]
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub enum FruitStyle {
     Circle,
     Square,
     Triangle,
 }
 
-#[derive(Hash, PartialEq, Eq, Clone, Copy)]
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub enum FruitColor {
     Red,
     Purple,
     Yellow,
 }
 
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Lifespan {
+    Annual,
+    Perennial,
+}
+
+impl Lifespan {
+    /// Gets the number of seasons a plant with this lifespan can live for, if it's limited.
+    pub fn max_age(&self) -> Option<u32> {
+        match self {
+            Lifespan::Annual => Some(1),
+            Lifespan::Perennial => None,
+        }
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct IntellectGene {
+    pub locus: u8,
+    pub allele: IntellectAllele,
+}
+
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum IntellectAllele {
+    Low,
+    High,
+}
+
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum FlowerType {
+    Male,
+    Female,
+    Hermaphrodite,
+}
+
+impl FlowerType {
+    /// Determines whether plants with this flower type make pollen.
+    pub fn has_pollen(&self) -> bool {
+        matches!(self, FlowerType::Male | FlowerType::Hermaphrodite)
+    }
+
+    /// Determines whether plants with this flower type can receive pollen.
+    pub fn has_pistil(&self) -> bool {
+        matches!(self, FlowerType::Female | FlowerType::Hermaphrodite)
+    }
+}
+
+impl std::fmt::Display for FlowerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlowerType::Male => write!(f, "male"),
+            FlowerType::Female => write!(f, "female"),
+            FlowerType::Hermaphrodite => write!(f, "hermaphrodite"),
+        }
+    }
+}
+
+impl std::fmt::Display for Lifespan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Lifespan::Annual => write!(f, "annual"),
+            Lifespan::Perennial => write!(f, "perennial"),
+        }
+    }
+}
+
 impl Gene {
+    pub fn category(&self) -> GeneCategory {
+        self.category
+    }
+
     pub fn new_with_category(category: GeneCategory) -> Gene {
         match category {
             GeneCategory::StemStyle(x) => Gene::new_with_stem_style(x),
             GeneCategory::StemColor(x) => Gene::new_with_stem_color(x),
             GeneCategory::FruitStyle(x) => Gene::new_with_fruit_style(x),
             GeneCategory::FruitColor(x) => Gene::new_with_fruit_color(x),
+            GeneCategory::Lifespan(x) => Gene::new_with_lifespan(x),
+            GeneCategory::Intellect(x) => Gene::new_with_intellect(x),
+            GeneCategory::FlowerType(x) => Gene::new_with_flower_type(x),
         }
     }
 
@@ -522,4 +1148,58 @@ impl Gene {
             pest_resistance_effect,
         }
     }
+
+    fn new_with_lifespan(lifespan: Lifespan) -> Gene {
+        let dominance;
+        let intelligence_effect;
+        let pest_resistance_effect;
+        match lifespan {
+            Lifespan::Annual => {
+                dominance = GeneDominance::Recessive;
+                intelligence_effect = 1;
+                pest_resistance_effect = 1;
+            }
+            Lifespan::Perennial => {
+                dominance = GeneDominance::Dominant;
+                intelligence_effect = 0;
+                pest_resistance_effect = 0;
+            }
+        }
+
+        Gene {
+            category: GeneCategory::Lifespan(lifespan),
+            dominance,
+            intelligence_effect,
+            pest_resistance_effect,
+        }
+    }
+
+    fn new_with_intellect(intellect: IntellectGene) -> Gene {
+        let intelligence_effect = match intellect.allele {
+            IntellectAllele::Low => 0,
+            IntellectAllele::High => 1,
+        };
+
+        Gene {
+            category: GeneCategory::Intellect(intellect),
+            dominance: GeneDominance::Additive,
+            intelligence_effect,
+            pest_resistance_effect: 0,
+        }
+    }
+
+    fn new_with_flower_type(flower_type: FlowerType) -> Gene {
+        let dominance = match flower_type {
+            FlowerType::Male => GeneDominance::Dominant,
+            FlowerType::Female => GeneDominance::Recessive,
+            FlowerType::Hermaphrodite => GeneDominance::Recessive,
+        };
+
+        Gene {
+            category: GeneCategory::FlowerType(flower_type),
+            dominance,
+            intelligence_effect: 0,
+            pest_resistance_effect: 0,
+        }
+    }
 }
@@ -0,0 +1,609 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::*;
+
+const RECORDS_FILE: &str = "records.txt";
+
+// only the best few runs fit on the records screen
+const MAX_SHOWN_RECORDS: usize = 8;
+
+const SECONDS_PER_DAY: u64 = 60 * 60 * 24;
+
+pub struct RecordsPlugin;
+
+impl Plugin for RecordsPlugin {
+    fn build(&self, app: &mut App) {
+        // the plant images are usually loaded when a game starts, but the records screen can be opened before that
+        app.add_loading_state(
+            LoadingState::new(GameState::RecordsLoading)
+                .continue_to_state(GameState::Records)
+                .with_collection::<ImageAssets>(),
+        );
+
+        app.add_system_set(
+            SystemSet::on_enter(GameState::GameLoading).with_system(reset_plants_lost_system),
+        )
+        .add_system_set(SystemSet::on_enter(GameState::Records).with_system(records_setup))
+        .add_system_set(
+            SystemSet::on_update(GameState::Records)
+                .with_system(records_button_system)
+                .with_system(records_display_system.after(records_button_system)),
+        )
+        .add_system_set(
+            SystemSet::on_exit(GameState::Records)
+                .with_system(despawn_components_system::<RecordsComponent>)
+                .with_system(despawn_components_system::<RecordPlantComponent>),
+        )
+        .add_system(count_plants_lost_system)
+        .add_system(save_record_system)
+        .insert_resource(PlantsLost(0))
+        .insert_resource(RecordsView::default());
+    }
+}
+
+/// How many plants have died in the game that's being played.
+pub struct PlantsLost(pub u32);
+
+/// A game the player finished, saved so they can try to beat it.
+#[derive(Clone)]
+pub struct RunRecord {
+    /// The day the game ended, as year-month-day.
+    pub date: String,
+    pub outcome: RunOutcome,
+    /// The seed for the random number generator for plants and seeds.
+    pub seed: u64,
+    pub seasons: u32,
+    pub plants_lost: u32,
+    /// The plant that won the game, if it was won.
+    pub plant: Option<Plant>,
+}
+
+/// How a saved game ended. Won games sort before lost ones.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RunOutcome {
+    Won,
+    Lost,
+}
+
+/// What the best runs are ordered by on the records screen.
+#[derive(Clone, Copy, Default)]
+pub enum RecordSort {
+    #[default]
+    Seasons,
+    PlantsLost,
+    Newest,
+}
+
+impl RecordSort {
+    fn next(self) -> RecordSort {
+        match self {
+            RecordSort::Seasons => RecordSort::PlantsLost,
+            RecordSort::PlantsLost => RecordSort::Newest,
+            RecordSort::Newest => RecordSort::Seasons,
+        }
+    }
+}
+
+impl std::fmt::Display for RecordSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordSort::Seasons => write!(f, "fewest seasons"),
+            RecordSort::PlantsLost => write!(f, "fewest plants lost"),
+            RecordSort::Newest => write!(f, "newest"),
+        }
+    }
+}
+
+/// The saved runs shown on the records screen, in the order they're shown, and which one's plant is being shown.
+#[derive(Default)]
+pub struct RecordsView {
+    pub records: Vec<RunRecord>,
+    pub sort: RecordSort,
+    pub selected: usize,
+}
+
+impl RecordsView {
+    fn sort_records(&mut self) {
+        // the best runs are always won ones, and ties go to the newer run, since it's more likely to be one the player remembers
+        match self.sort {
+            RecordSort::Seasons => self.records.sort_by(|a, b| {
+                (a.outcome, a.seasons, a.plants_lost, &b.date).cmp(&(
+                    b.outcome,
+                    b.seasons,
+                    b.plants_lost,
+                    &a.date,
+                ))
+            }),
+            RecordSort::PlantsLost => self.records.sort_by(|a, b| {
+                (a.outcome, a.plants_lost, a.seasons, &b.date).cmp(&(
+                    b.outcome,
+                    b.plants_lost,
+                    b.seasons,
+                    &a.date,
+                ))
+            }),
+            RecordSort::Newest => self.records.sort_by(|a, b| {
+                (&b.date, a.outcome, a.seasons, a.plants_lost).cmp(&(
+                    &a.date,
+                    b.outcome,
+                    b.seasons,
+                    b.plants_lost,
+                ))
+            }),
+        }
+        self.selected = 0;
+    }
+}
+
+#[derive(Component)]
+struct RecordsComponent;
+
+/// The winning plant that's being shown, which is redrawn whenever a different run is picked.
+#[derive(Component)]
+struct RecordPlantComponent;
+
+#[derive(Component)]
+struct RecordsList;
+
+#[derive(Component, Clone, Copy)]
+enum RecordsButton {
+    /// Shows the plant from the run at the provided index in the list.
+    Run(usize),
+    Sort,
+    Back,
+}
+
+/// Loads the saved runs, skipping any that can't be read.
+pub fn load_records() -> Vec<RunRecord> {
+    fs::read_to_string(RECORDS_FILE)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(read_record)
+        .collect()
+}
+
+/// Reads a run from a line of the records file, which has its date, whether it was won or lost, seed, seasons taken,
+/// plants lost and winning plant separated by spaces. Lost runs have no winning plant.
+fn read_record(line: &str) -> Option<RunRecord> {
+    let words = line.split_whitespace().collect::<Vec<_>>();
+
+    let outcome = match *words.get(1)? {
+        "won" => RunOutcome::Won,
+        "lost" => RunOutcome::Lost,
+        _ => return None,
+    };
+    let plant = match outcome {
+        RunOutcome::Won => Some(read_plant(words.get(5..)?)?),
+        RunOutcome::Lost => None,
+    };
+
+    Some(RunRecord {
+        date: words.first()?.to_string(),
+        outcome,
+        seed: words.get(2)?.parse().ok()?,
+        seasons: words.get(3)?.parse().ok()?,
+        plants_lost: words.get(4)?.parse().ok()?,
+        plant,
+    })
+}
+
+/// Adds a run to the end of the records file.
+fn save_record(record: &RunRecord) {
+    let outcome = match record.outcome {
+        RunOutcome::Won => "won",
+        RunOutcome::Lost => "lost",
+    };
+    let mut line = format!(
+        "{} {} {} {} {}",
+        record.date, outcome, record.seed, record.seasons, record.plants_lost
+    );
+    if let Some(plant) = &record.plant {
+        line.push_str(&format!(" {}", write_plant(plant)));
+    }
+    line.push('\n');
+
+    let result = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(RECORDS_FILE)
+        .and_then(|mut file| file.write_all(line.as_bytes()));
+    if let Err(e) = result {
+        warn!("Unable to save record: {e}");
+    }
+}
+
+/// Gets today's date in UTC as year-month-day, which sorts the same way as the dates themselves.
+/// Working out the local date would need the time zone, which the standard library can't get.
+fn get_date_today() -> String {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() / SECONDS_PER_DAY) as i64;
+
+    // converts days since 1970 to a date, counting in 400 year eras that start in March, so leap days come last
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+fn reset_plants_lost_system(mut plants_lost: ResMut<PlantsLost>) {
+    plants_lost.0 = 0;
+}
+
+fn count_plants_lost_system(
+    mut plants_lost: ResMut<PlantsLost>,
+    mut plant_died_events: EventReader<PlantDied>,
+) {
+    let count = plant_died_events.iter().count() as u32;
+    if count > 0 {
+        plants_lost.0 += count;
+    }
+}
+
+/// Saves the game the player just won or lost.
+/// Like replays, runs that were being watched and hot-seat games aren't saved.
+fn save_record_system(
+    replay: Res<Replay>,
+    playback: Res<ReplayPlayback>,
+    smart_plant: Res<SmartPlant>,
+    plants_lost: Res<PlantsLost>,
+    hot_seat: Res<HotSeat>,
+    mut game_won_events: EventReader<GameWon>,
+    mut game_lost_events: EventReader<GameLost>,
+) {
+    let won = game_won_events
+        .iter()
+        .map(|event| (RunOutcome::Won, event.season));
+    let lost = game_lost_events
+        .iter()
+        .map(|event| (RunOutcome::Lost, event.season));
+
+    for (outcome, season) in won.chain(lost) {
+        // there are no files to save to on the web, and no clock to get the date from
+        if cfg!(target_arch = "wasm32") || playback.is_running() || hot_seat.is_on() {
+            continue;
+        }
+
+        let plant = match outcome {
+            RunOutcome::Won => smart_plant.0.clone(),
+            RunOutcome::Lost => None,
+        };
+
+        save_record(&RunRecord {
+            date: get_date_today(),
+            outcome,
+            seed: replay.seed,
+            seasons: season,
+            plants_lost: plants_lost.0,
+            plant,
+        });
+    }
+}
+
+/// Sets up the records screen, with the best runs first.
+fn records_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut records_view: ResMut<RecordsView>,
+) {
+    let title_font = asset_server.load(TITLE_FONT);
+    let main_font = asset_server.load(MAIN_FONT);
+
+    records_view.records = load_records();
+    records_view.sort_records();
+
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(50.0), Val::Percent(100.0)),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::ColumnReverse,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            color: UiColor(Color::NONE),
+            ..default()
+        })
+        .insert(RecordsComponent)
+        .with_children(|parent| {
+            // header text
+            parent.spawn_bundle(
+                TextBundle::from_section(
+                    "Records",
+                    TextStyle {
+                        font: title_font.clone(),
+                        font_size: 60.0,
+                        color: Color::WHITE,
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect {
+                        bottom: Val::Px(20.0),
+                        ..default()
+                    },
+                    ..default()
+                }),
+            );
+
+            // the sort button and the runs are filled in by the display system
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::ColumnReverse,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    color: UiColor(Color::NONE),
+                    ..default()
+                })
+                .insert(RecordsList);
+
+            spawn_records_button(
+                parent,
+                RecordsButton::Back,
+                "Back".to_string(),
+                Color::SEA_GREEN,
+                Val::Px(200.0),
+                &main_font,
+            );
+        });
+}
+
+/// Spawns a button on the records screen.
+fn spawn_records_button(
+    parent: &mut ChildBuilder,
+    button: RecordsButton,
+    text: String,
+    text_color: Color,
+    width: Val,
+    font: &Handle<Font>,
+) {
+    parent
+        .spawn_bundle(ButtonBundle {
+            style: Style {
+                size: Size::new(width, Val::Px(50.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(5.0)),
+                ..default()
+            },
+            color: NORMAL_BUTTON.into(),
+            ..default()
+        })
+        .insert(button)
+        .with_children(|parent| {
+            parent.spawn_bundle(TextBundle::from_section(
+                text,
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 26.0,
+                    color: text_color,
+                },
+            ));
+        });
+}
+
+fn get_sort_text(sort: RecordSort) -> String {
+    format!("Sort by: {sort}")
+}
+
+fn get_record_text(record: &RunRecord) -> String {
+    match &record.plant {
+        Some(plant) => format!(
+            "{}: {} in {} seasons, {} lost",
+            record.date, plant.name, record.seasons, record.plants_lost
+        ),
+        None => format!(
+            "{}: lost in season {}, {} lost",
+            record.date, record.seasons, record.plants_lost
+        ),
+    }
+}
+
+type InteractedRecordsButtonTuple = (Changed<Interaction>, With<RecordsButton>);
+
+/// Handles interactions with the buttons on the records screen.
+fn records_button_system(
+    mut game_state: ResMut<State<GameState>>,
+    mut records_view: ResMut<RecordsView>,
+    interaction_query: Query<(&Interaction, &RecordsButton), InteractedRecordsButtonTuple>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            match button {
+                RecordsButton::Run(index) => records_view.selected = *index,
+                RecordsButton::Sort => {
+                    records_view.sort = records_view.sort.next();
+                    records_view.sort_records();
+                }
+                RecordsButton::Back => game_state.set(GameState::Menu).unwrap(),
+            }
+        }
+    }
+}
+
+/// Fills in the list of runs, and shows the plant from the picked one if it was won.
+/// This happens once the list has been spawned, and again whenever the order or the picked run changes.
+#[allow(clippy::too_many_arguments)]
+fn records_display_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    records_view: Res<RecordsView>,
+    display_settings: Res<PlantDisplaySettings>,
+    image_assets: Res<ImageAssets>,
+    list_query: Query<Entity, With<RecordsList>>,
+    added_list_query: Query<(), Added<RecordsList>>,
+    plant_query: Query<Entity, With<RecordPlantComponent>>,
+) {
+    if !records_view.is_changed() && added_list_query.is_empty() {
+        return;
+    }
+
+    let list = match list_query.get_single() {
+        Ok(list) => list,
+        Err(_) => return,
+    };
+
+    let title_font = asset_server.load(TITLE_FONT);
+    let main_font = asset_server.load(MAIN_FONT);
+
+    // sort button and runs
+    commands
+        .entity(list)
+        .despawn_descendants()
+        .with_children(|parent| {
+            spawn_records_button(
+                parent,
+                RecordsButton::Sort,
+                get_sort_text(records_view.sort),
+                Color::SEA_GREEN,
+                Val::Px(400.0),
+                &main_font,
+            );
+
+            for (index, record) in records_view
+                .records
+                .iter()
+                .take(MAX_SHOWN_RECORDS)
+                .enumerate()
+            {
+                let color = if index == records_view.selected {
+                    Color::ORANGE
+                } else {
+                    Color::SEA_GREEN
+                };
+
+                spawn_records_button(
+                    parent,
+                    RecordsButton::Run(index),
+                    get_record_text(record),
+                    color,
+                    Val::Px(560.0),
+                    &main_font,
+                );
+            }
+
+            if records_view.records.is_empty() {
+                parent.spawn_bundle(TextBundle::from_section(
+                    "No runs yet. Go grow a smart plant!",
+                    TextStyle {
+                        font: main_font.clone(),
+                        font_size: 30.0,
+                        color: Color::WHITE,
+                    },
+                ));
+            }
+        });
+
+    for entity in plant_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let record = match records_view.records.get(records_view.selected) {
+        Some(record) => record,
+        None => return,
+    };
+
+    // plant space
+    let plant_space_transform = Transform {
+        translation: Vec3::new(WINDOW_WIDTH / 4.0, 70.0, MIDDLE_LAYER),
+        ..default()
+    };
+    commands
+        .spawn_bundle(SpriteBundle {
+            texture: image_assets.plant_space.clone(),
+            transform: plant_space_transform,
+            ..default()
+        })
+        .insert(RecordPlantComponent);
+
+    if let Some(plant) = &record.plant {
+        // plant image
+        spawn_plant_image(
+            &mut commands,
+            &plant_space_transform,
+            &plant.get_phenotype(),
+            &display_settings,
+            &image_assets,
+            0,
+            RecordPlantComponent,
+        );
+
+        // plant glasses
+        commands
+            .spawn_bundle(SpriteBundle {
+                texture: image_assets.glasses.clone(),
+                transform: Transform {
+                    translation: Vec3::new(
+                        plant_space_transform.translation.x,
+                        plant_space_transform.translation.y
+                            + ((PLANT_SPACE_HEIGHT - PLANT_SPACE_SIZE) / 2.0),
+                        PLANTS_LAYER + 2.0,
+                    ),
+                    ..default()
+                },
+                ..default()
+            })
+            .insert(RecordPlantComponent);
+    }
+
+    let (title, outcome) = match &record.plant {
+        Some(plant) => (plant.name.to_string(), "Won"),
+        None => ("No smart plant".to_string(), "Lost"),
+    };
+
+    // plant name and details
+    commands
+        .spawn_bundle(Text2dBundle {
+            text: Text::from_sections([
+                TextSection::new(
+                    format!("{title}\n"),
+                    TextStyle {
+                        font: title_font,
+                        font_size: 40.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                TextSection::new(
+                    format!(
+                        "{} on {} (UTC) in {} seasons, losing {} plants\nSeed {}",
+                        outcome, record.date, record.seasons, record.plants_lost, record.seed
+                    ),
+                    TextStyle {
+                        font: main_font,
+                        font_size: 24.0,
+                        color: Color::WHITE,
+                    },
+                ),
+            ])
+            .with_alignment(TextAlignment::TOP_CENTER),
+            transform: Transform {
+                translation: Vec3::new(
+                    plant_space_transform.translation.x,
+                    plant_space_transform.translation.y - (PLANT_SPACE_HEIGHT / 2.0) - 10.0,
+                    MIDDLE_LAYER,
+                ),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(RecordPlantComponent);
+}
@@ -0,0 +1,185 @@
+use std::{fs, path::Path};
+
+use crate::*;
+
+const REPLAY_FILE: &str = "replay.txt";
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_exit(GameState::Game).with_system(save_replay_system))
+            .insert_resource(Replay::default())
+            .insert_resource(ReplayPlayback::default());
+    }
+}
+
+/// Something the player did that changed the game, with enough detail to do it again.
+#[derive(Clone, Copy)]
+pub enum ReplayAction {
+    /// Made seeds from the plants in the provided planters, with the first plant providing the pollen.
+    MakeSeeds {
+        pollen_planter: usize,
+        pistil_planter: usize,
+        origin: SeedOrigin,
+    },
+    KeepSeed(usize),
+    DiscardSeedPod,
+    PlantSeed {
+        seed: usize,
+        planter: usize,
+    },
+    MovePlant {
+        from: usize,
+        to: usize,
+        swap: bool,
+    },
+    NextSeason,
+}
+
+/// Everything needed to play a game again exactly as it happened.
+#[derive(Default)]
+pub struct Replay {
+    /// The seed for the random number generator for plants and seeds.
+    pub seed: u64,
+    pub sexed_flowers: bool,
+    /// Whether the game was played against a rival.
+    pub versus: bool,
+    pub actions: Vec<ReplayAction>,
+}
+
+/// The replay that's being played back, if there is one, and how far through it is.
+#[derive(Default)]
+pub struct ReplayPlayback {
+    pub replay: Option<Replay>,
+    pub next_action: usize,
+    pub timer: Timer,
+}
+
+impl ReplayPlayback {
+    pub fn is_running(&self) -> bool {
+        self.replay.is_some()
+    }
+}
+
+impl std::fmt::Display for ReplayAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayAction::MakeSeeds {
+                pollen_planter,
+                pistil_planter,
+                origin,
+            } => write!(
+                f,
+                "make_seeds {pollen_planter} {pistil_planter} {}",
+                write_seed_origin(*origin)
+            ),
+            ReplayAction::KeepSeed(index) => write!(f, "keep_seed {index}"),
+            ReplayAction::DiscardSeedPod => write!(f, "discard_seed_pod"),
+            ReplayAction::PlantSeed { seed, planter } => write!(f, "plant_seed {seed} {planter}"),
+            ReplayAction::MovePlant { from, to, swap } => {
+                let how = if *swap { "swap" } else { "move" };
+                write!(f, "move_plant {from} {to} {how}")
+            }
+            ReplayAction::NextSeason => write!(f, "next_season"),
+        }
+    }
+}
+
+impl ReplayAction {
+    /// Reads an action from a line of a replay file, if it's a valid one.
+    fn parse(line: &str) -> Option<ReplayAction> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let number = |index: usize| words.get(index)?.parse::<usize>().ok();
+
+        let action = match *words.first()? {
+            "make_seeds" => ReplayAction::MakeSeeds {
+                pollen_planter: number(1)?,
+                pistil_planter: number(2)?,
+                origin: read_seed_origin(words.get(3)?)?,
+            },
+            "keep_seed" => ReplayAction::KeepSeed(number(1)?),
+            "discard_seed_pod" => ReplayAction::DiscardSeedPod,
+            "plant_seed" => ReplayAction::PlantSeed {
+                seed: number(1)?,
+                planter: number(2)?,
+            },
+            "move_plant" => ReplayAction::MovePlant {
+                from: number(1)?,
+                to: number(2)?,
+                swap: *words.get(3)? == "swap",
+            },
+            "next_season" => ReplayAction::NextSeason,
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
+
+/// Whether there's a saved replay to watch.
+pub fn replay_exists() -> bool {
+    Path::new(REPLAY_FILE).exists()
+}
+
+/// Loads the saved replay, if there is one that can be read.
+/// Any actions that can't be read are skipped, since the rest of the replay might still be worth watching.
+pub fn load_replay() -> Option<Replay> {
+    let contents = fs::read_to_string(REPLAY_FILE).ok()?;
+    let mut replay = Replay::default();
+    let mut has_seed = false;
+
+    for line in contents.lines() {
+        if let Some((key, value)) = line.split_once('=') {
+            match key.trim() {
+                "seed" => {
+                    if let Ok(seed) = value.trim().parse::<u64>() {
+                        replay.seed = seed;
+                        has_seed = true;
+                    }
+                }
+                "sexed_flowers" => replay.sexed_flowers = value.trim() == "true",
+                "versus" => replay.versus = value.trim() == "true",
+                _ => (),
+            }
+        } else if let Some(action) = ReplayAction::parse(line) {
+            replay.actions.push(action);
+        } else if !line.trim().is_empty() {
+            warn!("Skipping unreadable replay action: {line}");
+        }
+    }
+
+    // without the seed, none of the actions would do the same thing they did originally
+    has_seed.then_some(replay)
+}
+
+/// Saves the provided replay so it can be watched later.
+fn save_replay(replay: &Replay) {
+    let mut contents = format!(
+        "seed={}\nsexed_flowers={}\nversus={}\n",
+        replay.seed, replay.sexed_flowers, replay.versus
+    );
+    for action in &replay.actions {
+        contents.push_str(&format!("{action}\n"));
+    }
+
+    if let Err(e) = fs::write(REPLAY_FILE, contents) {
+        warn!("Unable to save replay: {e}");
+    }
+}
+
+/// Saves the replay of the game that just ended.
+/// A replay that was being watched isn't saved again, unless it finished and the player carried on from there.
+/// Hot-seat games and LAN matches aren't saved either, since trades and turns aren't part of replays.
+fn save_replay_system(
+    replay: Res<Replay>,
+    mut playback: ResMut<ReplayPlayback>,
+    hot_seat: Res<HotSeat>,
+    lan: Res<Lan>,
+) {
+    if playback.is_running() {
+        playback.replay = None;
+    } else if !hot_seat.is_on() && lan.lan_match.is_none() {
+        save_replay(&replay);
+    }
+}
@@ -0,0 +1,270 @@
+use crate::*;
+
+// the most things the rival can do in a season, so it can't get stuck doing the same thing forever
+const MAX_RIVAL_ACTIONS_PER_SEASON: usize = 20;
+
+const RIVAL_PANEL_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.5);
+const RIVAL_PANEL_WIDTH: f32 = 260.0;
+
+pub struct RivalPlugin;
+
+impl Plugin for RivalPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_enter(GameState::Game).with_system(rival_setup.after(GameSystem::Setup)),
+        )
+        .add_system(rival_panel_display_system.with_run_criteria(is_set_up))
+        .add_system(rival_panel_button_system)
+        .insert_resource(VersusMode(false))
+        .insert_resource(Rival::default());
+    }
+}
+
+#[derive(Component)]
+struct RivalPanelButton;
+
+#[derive(Component)]
+struct RivalPanelText;
+
+/// Whether the player is racing a rival breeder to grow a smart plant first.
+pub struct VersusMode(pub bool);
+
+/// The rival breeder's farm, which the computer looks after alongside the player's in versus mode.
+#[derive(Default)]
+pub struct Rival {
+    pub planters: Planters,
+    pub seeds: Seeds,
+    /// What happened to the rival's plants the last time the season changed.
+    pub last_report: Option<SeasonReport>,
+    /// The plant the rival won with, if it grew a smart enough plant first.
+    pub smart_plant: Option<Plant>,
+}
+
+impl Rival {
+    /// Sets up a rival with a copy of the provided plants.
+    pub fn new(planters: &Planters) -> Rival {
+        Rival {
+            planters: planters.clone(),
+            ..default()
+        }
+    }
+
+    /// Determines whether the rival has nothing left to grow, so it can't win any more.
+    pub fn is_out(&self) -> bool {
+        is_out_of_plants(&self.planters, &self.seeds)
+    }
+
+    /// Makes the rival's moves for the season, then moves its plants on to the provided season.
+    pub fn play_season(&mut self, season: u32, sexed_flowers: bool) {
+        if self.is_out() {
+            return;
+        }
+
+        self.take_turn(sexed_flowers);
+
+        let mut report = SeasonReport {
+            season,
+            ..self.planters.grow()
+        };
+        let pest_victims = self.planters.choose_pest_victims();
+        self.planters.unleash_pests(&pest_victims, &mut report);
+        report.stats = self.planters.get_phenotype_stats();
+
+        self.last_report = Some(report);
+    }
+
+    /// Splices, keeps and plants seeds by following the hints, just like a player who always asks for one would.
+    fn take_turn(&mut self, sexed_flowers: bool) {
+        let mut seed_pod = Vec::new();
+
+        for _ in 0..MAX_RIVAL_ACTIONS_PER_SEASON {
+            match find_hint(&self.planters, &self.seeds, &seed_pod, sexed_flowers) {
+                Hint::MakeSeeds {
+                    pollen_planter,
+                    pistil_planter,
+                    origin,
+                    ..
+                } => {
+                    if let (Some(Planter::Plant(plant_1)), Some(Planter::Plant(plant_2))) = (
+                        self.planters.0.get(pollen_planter),
+                        self.planters.0.get(pistil_planter),
+                    ) {
+                        match origin {
+                            SeedOrigin::Spliced => {
+                                seed_pod = (0..SEEDS_PER_POD)
+                                    .map(|_| splice_plants(plant_1, plant_2))
+                                    .collect();
+                            }
+                            SeedOrigin::SelfPollinated => {
+                                seed_pod = (0..SEEDS_PER_POD)
+                                    .map(|_| self_pollinate(plant_1))
+                                    .collect();
+                            }
+                            SeedOrigin::Cutting => self.seeds.0.push(take_cutting(plant_1)),
+                        }
+                    }
+                }
+                Hint::KeepSeed { index, .. } => {
+                    // only the best seed from each pod is kept, to leave room for more pods
+                    self.seeds.0.push(seed_pod.swap_remove(index));
+                    seed_pod.clear();
+                }
+                Hint::DiscardSeedPod => seed_pod.clear(),
+                Hint::PlantSeed { seed, planter, .. } => {
+                    let seed = self.seeds.0.remove(seed);
+                    self.planters.0[planter] = Planter::Seed(seed);
+                }
+                Hint::NextSeason => break,
+            }
+        }
+
+        // unlike the hints, the rival is happy to pull up a plant to make room for a better seed
+        while let Some((seed, planter)) = find_replacement(&self.planters, &self.seeds) {
+            let seed = self.seeds.0.remove(seed);
+            self.planters.0[planter] = Planter::Seed(seed);
+        }
+    }
+}
+
+/// Gives the rival a copy of the starting plants in versus mode, along with a panel showing how it's doing.
+fn rival_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    versus_mode: Res<VersusMode>,
+    planters: Res<Planters>,
+    mut rival: ResMut<Rival>,
+) {
+    if !versus_mode.0 {
+        *rival = Rival::default();
+        return;
+    }
+
+    *rival = Rival::new(&planters);
+
+    let main_font = asset_server.load(MAIN_FONT);
+    let title_font = asset_server.load(TITLE_FONT);
+
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Px(RIVAL_PANEL_WIDTH), Val::Auto),
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    top: Val::Px(TOP_BAR_HEIGHT + 10.0),
+                    right: Val::Px(10.0),
+                    ..default()
+                },
+                flex_direction: FlexDirection::ColumnReverse,
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            color: RIVAL_PANEL_COLOR.into(),
+            ..default()
+        })
+        .insert(GameComponent)
+        .with_children(|parent| {
+            // the panel starts out folded up so it doesn't cover the plants, and this button unfolds it
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Percent(100.0), Val::Px(40.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    color: NORMAL_BUTTON.into(),
+                    ..default()
+                })
+                .insert(RivalPanelButton)
+                .with_children(|parent| {
+                    parent.spawn_bundle(TextBundle::from_section(
+                        "Rival",
+                        TextStyle {
+                            font: title_font.clone(),
+                            font_size: 30.0,
+                            color: Color::WHITE,
+                        },
+                    ));
+                });
+
+            parent
+                .spawn_bundle(
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font: main_font.clone(),
+                            font_size: 20.0,
+                            color: Color::WHITE,
+                        },
+                    )
+                    .with_style(Style {
+                        display: Display::None,
+                        margin: UiRect {
+                            top: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    }),
+                )
+                .insert(RivalPanelText);
+        });
+}
+
+type InteractedRivalPanelButtonTuple = (Changed<Interaction>, With<RivalPanelButton>);
+
+/// Handles interactions with the rival panel's button, which folds the panel up or out
+fn rival_panel_button_system(
+    mut rival_panel_text_query: Query<&mut Style, With<RivalPanelText>>,
+    interaction_query: Query<&Interaction, InteractedRivalPanelButtonTuple>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            for mut style in rival_panel_text_query.iter_mut() {
+                style.display = match style.display {
+                    Display::None => Display::Flex,
+                    Display::Flex => Display::None,
+                };
+            }
+        }
+    }
+}
+
+/// Keeps the summary of the rival's progress up to date.
+fn rival_panel_display_system(
+    rival: Res<Rival>,
+    mut rival_panel_text_query: Query<&mut Text, With<RivalPanelText>>,
+) {
+    if !rival.is_changed() {
+        return;
+    }
+
+    for mut text in rival_panel_text_query.iter_mut() {
+        text.sections[0].value = get_rival_panel_text(&rival);
+    }
+}
+
+/// Gets the summary of the rival's progress to show on its panel.
+fn get_rival_panel_text(rival: &Rival) -> String {
+    if rival.is_out() {
+        return "All their plants have died,\nso they're out of the race!".to_string();
+    }
+
+    let mut text = match rival.planters.get_phenotype_stats() {
+        Some(stats) => format!(
+            "Best intelligence: {}/{}\nPlants: {}",
+            stats.best_intelligence, GOAL_INTELLIGENCE, stats.living_plants
+        ),
+        None => "Best intelligence: -\nPlants: 0".to_string(),
+    };
+    text.push_str(&format!("\nSeeds: {}", rival.seeds.0.len()));
+
+    if let Some(report) = &rival.last_report {
+        text.push_str(&format!(
+            "\nLast season: {} grew, {} died",
+            report.grown.len(),
+            report.deaths.len()
+        ));
+    }
+
+    text
+}
@@ -0,0 +1,462 @@
+use std::fs;
+
+use bevy::window::WindowMode;
+
+use crate::*;
+
+const SETTINGS_FILE: &str = "settings.cfg";
+
+const VOLUME_STEP: f64 = 0.1;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        let (settings, display_settings) = load_settings();
+
+        app.add_system_set(SystemSet::on_enter(GameState::Settings).with_system(settings_setup))
+            .add_system_set(
+                SystemSet::on_exit(GameState::Settings)
+                    .with_system(despawn_components_system::<SettingsComponent>),
+            )
+            .add_system(settings_button_system)
+            .add_system(settings_text_system.after(settings_button_system))
+            .add_system(fullscreen_system)
+            .add_system(window_mode_system.after(fullscreen_system))
+            .add_system(
+                save_settings_system
+                    .after(settings_button_system)
+                    .after(fullscreen_system),
+            )
+            .insert_resource(settings)
+            .insert_resource(display_settings);
+    }
+}
+
+/// Options that the player can change, which are saved between runs of the game.
+pub struct Settings {
+    pub music_volume: f64,
+    pub sfx_volume: f64,
+    pub fullscreen: bool,
+    /// Whether to ask before doing things that lose the player's progress.
+    pub confirmations: bool,
+    /// Whether to turn on the developer tools, which can only be set in the settings file.
+    pub dev_mode: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            music_volume: 1.0,
+            sfx_volume: 1.0,
+            fullscreen: false,
+            confirmations: true,
+            dev_mode: DEV_MODE,
+        }
+    }
+}
+
+#[derive(Component)]
+struct SettingsComponent;
+
+#[derive(Component, Clone, Copy)]
+enum SettingsButton {
+    MusicVolumeDown,
+    MusicVolumeUp,
+    SfxVolumeDown,
+    SfxVolumeUp,
+    WindowMode,
+    Confirmations,
+    ColorPalette,
+    FruitPatterns,
+    Back,
+}
+
+#[derive(Component, Clone, Copy)]
+enum SettingText {
+    MusicVolume,
+    SfxVolume,
+    WindowMode,
+    Confirmations,
+    ColorPalette,
+    FruitPatterns,
+}
+
+/// Loads the saved settings, using the defaults for anything that's missing or can't be read.
+fn load_settings() -> (Settings, PlantDisplaySettings) {
+    let mut settings = Settings::default();
+    let mut display_settings = PlantDisplaySettings {
+        palette: ColorPalette::Standard,
+        fruit_patterns: false,
+    };
+
+    // there won't be a settings file the first time the game is run, or on the web
+    let contents = fs::read_to_string(SETTINGS_FILE).unwrap_or_default();
+
+    for line in contents.lines() {
+        if let Some((key, value)) = line.split_once('=') {
+            let value = value.trim();
+            match key.trim() {
+                "music_volume" => {
+                    if let Ok(volume) = value.parse::<f64>() {
+                        settings.music_volume = volume.clamp(0.0, 1.0);
+                    }
+                }
+                "sfx_volume" => {
+                    if let Ok(volume) = value.parse::<f64>() {
+                        settings.sfx_volume = volume.clamp(0.0, 1.0);
+                    }
+                }
+                "fullscreen" => settings.fullscreen = value == "true",
+                "confirmations" => settings.confirmations = value == "true",
+                "dev_mode" => settings.dev_mode = DEV_MODE || value == "true",
+                "color_palette" => {
+                    display_settings.palette = match value {
+                        "deuteranopia" => ColorPalette::Deuteranopia,
+                        "protanopia" => ColorPalette::Protanopia,
+                        "tritanopia" => ColorPalette::Tritanopia,
+                        _ => ColorPalette::Standard,
+                    }
+                }
+                "fruit_patterns" => display_settings.fruit_patterns = value == "true",
+                _ => (),
+            }
+        }
+    }
+
+    (settings, display_settings)
+}
+
+/// Saves the provided settings so they can be loaded the next time the game is run.
+fn save_settings(settings: &Settings, display_settings: &PlantDisplaySettings) {
+    let palette = match display_settings.palette {
+        ColorPalette::Standard => "standard",
+        ColorPalette::Deuteranopia => "deuteranopia",
+        ColorPalette::Protanopia => "protanopia",
+        ColorPalette::Tritanopia => "tritanopia",
+    };
+
+    let contents = format!(
+        "music_volume={:.1}\nsfx_volume={:.1}\nfullscreen={}\nconfirmations={}\ndev_mode={}\ncolor_palette={}\nfruit_patterns={}\n",
+        settings.music_volume,
+        settings.sfx_volume,
+        settings.fullscreen,
+        settings.confirmations,
+        settings.dev_mode,
+        palette,
+        display_settings.fruit_patterns,
+    );
+
+    if let Err(e) = fs::write(SETTINGS_FILE, contents) {
+        warn!("Unable to save settings: {e}");
+    }
+}
+
+/// Sets up the settings screen.
+fn settings_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    display_settings: Res<PlantDisplaySettings>,
+) {
+    let title_font = asset_server.load(TITLE_FONT);
+    let main_font = asset_server.load(MAIN_FONT);
+
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::ColumnReverse,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            color: Color::rgba(0.1, 0.1, 0.1, 0.99).into(),
+            ..default()
+        })
+        .insert(SettingsComponent)
+        .with_children(|parent| {
+            // header text
+            parent.spawn_bundle(
+                TextBundle::from_section(
+                    "Settings",
+                    TextStyle {
+                        font: title_font.clone(),
+                        font_size: 60.0,
+                        color: Color::WHITE,
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect {
+                        bottom: Val::Px(30.0),
+                        ..default()
+                    },
+                    ..default()
+                }),
+            );
+
+            spawn_volume_row(
+                parent,
+                SettingText::MusicVolume,
+                get_setting_text(SettingText::MusicVolume, &settings, &display_settings),
+                SettingsButton::MusicVolumeDown,
+                SettingsButton::MusicVolumeUp,
+                &main_font,
+            );
+            spawn_volume_row(
+                parent,
+                SettingText::SfxVolume,
+                get_setting_text(SettingText::SfxVolume, &settings, &display_settings),
+                SettingsButton::SfxVolumeDown,
+                SettingsButton::SfxVolumeUp,
+                &main_font,
+            );
+
+            for (button, setting_text) in [
+                (SettingsButton::WindowMode, SettingText::WindowMode),
+                (SettingsButton::Confirmations, SettingText::Confirmations),
+                (SettingsButton::ColorPalette, SettingText::ColorPalette),
+                (SettingsButton::FruitPatterns, SettingText::FruitPatterns),
+            ] {
+                spawn_settings_button(
+                    parent,
+                    button,
+                    Some(setting_text),
+                    get_setting_text(setting_text, &settings, &display_settings),
+                    Val::Px(400.0),
+                    &main_font,
+                );
+            }
+
+            spawn_settings_button(
+                parent,
+                SettingsButton::Back,
+                None,
+                "Back".to_string(),
+                Val::Px(200.0),
+                &main_font,
+            );
+        });
+}
+
+/// Spawns a row with buttons for turning a volume down and up on either side of its current value.
+fn spawn_volume_row(
+    parent: &mut ChildBuilder,
+    setting_text: SettingText,
+    text: String,
+    down_button: SettingsButton,
+    up_button: SettingsButton,
+    font: &Handle<Font>,
+) {
+    parent
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Px(600.0), Val::Px(60.0)),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            color: UiColor(Color::NONE),
+            ..default()
+        })
+        .with_children(|parent| {
+            spawn_settings_button(
+                parent,
+                down_button,
+                None,
+                "-".to_string(),
+                Val::Px(60.0),
+                font,
+            );
+
+            parent
+                .spawn_bundle(TextBundle::from_section(
+                    text,
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 30.0,
+                        color: Color::WHITE,
+                    },
+                ))
+                .insert(setting_text);
+
+            spawn_settings_button(
+                parent,
+                up_button,
+                None,
+                "+".to_string(),
+                Val::Px(60.0),
+                font,
+            );
+        });
+}
+
+/// Spawns a button on the settings screen, with its text marked as showing the provided setting.
+fn spawn_settings_button(
+    parent: &mut ChildBuilder,
+    button: SettingsButton,
+    setting_text: Option<SettingText>,
+    text: String,
+    width: Val,
+    font: &Handle<Font>,
+) {
+    parent
+        .spawn_bundle(ButtonBundle {
+            style: Style {
+                size: Size::new(width, Val::Px(50.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(5.0)),
+                ..default()
+            },
+            color: NORMAL_BUTTON.into(),
+            ..default()
+        })
+        .insert(button)
+        .with_children(|parent| {
+            let mut text = parent.spawn_bundle(TextBundle::from_section(
+                text,
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 30.0,
+                    color: Color::SEA_GREEN,
+                },
+            ));
+
+            if let Some(setting_text) = setting_text {
+                text.insert(setting_text);
+            }
+        });
+}
+
+fn get_setting_text(
+    setting_text: SettingText,
+    settings: &Settings,
+    display_settings: &PlantDisplaySettings,
+) -> String {
+    let on_off = |on: bool| if on { "on" } else { "off" };
+
+    match setting_text {
+        SettingText::MusicVolume => {
+            format!("Music volume: {}%", (settings.music_volume * 100.0).round())
+        }
+        SettingText::SfxVolume => format!(
+            "Sound effects volume: {}%",
+            (settings.sfx_volume * 100.0).round()
+        ),
+        SettingText::WindowMode => {
+            if settings.fullscreen {
+                "Window mode: fullscreen".to_string()
+            } else {
+                "Window mode: windowed".to_string()
+            }
+        }
+        SettingText::Confirmations => format!("Confirmations: {}", on_off(settings.confirmations)),
+        SettingText::ColorPalette => format!("Colors: {}", display_settings.palette),
+        SettingText::FruitPatterns => {
+            format!(
+                "Fruit patterns: {}",
+                on_off(display_settings.fruit_patterns)
+            )
+        }
+    }
+}
+
+type InteractedSettingsButtonTuple = (Changed<Interaction>, With<SettingsButton>);
+
+/// Handles interactions with the buttons on the settings screen.
+fn settings_button_system(
+    mut game_state: ResMut<State<GameState>>,
+    mut settings: ResMut<Settings>,
+    mut display_settings: ResMut<PlantDisplaySettings>,
+    interaction_query: Query<(&Interaction, &SettingsButton), InteractedSettingsButtonTuple>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            match button {
+                SettingsButton::MusicVolumeDown => {
+                    settings.music_volume = (settings.music_volume - VOLUME_STEP).max(0.0)
+                }
+                SettingsButton::MusicVolumeUp => {
+                    settings.music_volume = (settings.music_volume + VOLUME_STEP).min(1.0)
+                }
+                SettingsButton::SfxVolumeDown => {
+                    settings.sfx_volume = (settings.sfx_volume - VOLUME_STEP).max(0.0)
+                }
+                SettingsButton::SfxVolumeUp => {
+                    settings.sfx_volume = (settings.sfx_volume + VOLUME_STEP).min(1.0)
+                }
+                SettingsButton::WindowMode => settings.fullscreen = !settings.fullscreen,
+                SettingsButton::Confirmations => settings.confirmations = !settings.confirmations,
+                SettingsButton::ColorPalette => {
+                    display_settings.palette = display_settings.palette.next()
+                }
+                SettingsButton::FruitPatterns => {
+                    display_settings.fruit_patterns = !display_settings.fruit_patterns
+                }
+                SettingsButton::Back => {
+                    // the settings screen is pushed on top of the game when it's opened from the pause menu
+                    if game_state.inactives().is_empty() {
+                        game_state.set(GameState::Menu).unwrap();
+                    } else {
+                        game_state.pop().unwrap();
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Keeps the text on the settings screen up to date with the current settings.
+fn settings_text_system(
+    settings: Res<Settings>,
+    display_settings: Res<PlantDisplaySettings>,
+    mut text_query: Query<(&mut Text, &SettingText)>,
+) {
+    if !settings.is_changed() && !display_settings.is_changed() {
+        return;
+    }
+
+    for (mut text, setting_text) in text_query.iter_mut() {
+        text.sections[0].value = get_setting_text(*setting_text, &settings, &display_settings);
+    }
+}
+
+/// Handles switching between windowed and fullscreen with a shortcut key.
+fn fullscreen_system(keyboard: Res<Input<KeyCode>>, mut settings: ResMut<Settings>) {
+    if keyboard.just_pressed(KeyCode::F11) {
+        settings.fullscreen = !settings.fullscreen;
+    }
+}
+
+/// Keeps the window mode in line with the settings.
+fn window_mode_system(settings: Res<Settings>, mut windows: ResMut<Windows>) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    if let Some(window) = windows.get_primary_mut() {
+        let mode = if settings.fullscreen {
+            WindowMode::BorderlessFullscreen
+        } else {
+            WindowMode::Windowed
+        };
+
+        if window.mode() != mode {
+            window.set_mode(mode);
+        }
+    }
+}
+
+/// Saves the settings whenever they change.
+fn save_settings_system(settings: Res<Settings>, display_settings: Res<PlantDisplaySettings>) {
+    // they were just loaded when they were added, so there's no need to save them again
+    if settings.is_added() {
+        return;
+    }
+
+    if settings.is_changed() || display_settings.is_changed() {
+        save_settings(&settings, &display_settings);
+    }
+}
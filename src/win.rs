@@ -29,6 +29,8 @@ fn win_setup(
     asset_server: Res<AssetServer>,
     season: Res<Season>,
     smart_plant: Res<SmartPlant>,
+    hot_seat: Res<HotSeat>,
+    display_settings: Res<PlantDisplaySettings>,
     image_assets: Res<ImageAssets>,
 ) {
     let title_font = asset_server.load(TITLE_FONT);
@@ -37,6 +39,15 @@ fn win_setup(
     // gotta have a smart plant if we're at this screen
     let plant = smart_plant.0.as_ref().unwrap();
 
+    let header_text = match hot_seat.winner {
+        Some(player) => format!(
+            "After {} seasons, Player {} grew a real smart plant:",
+            season.0,
+            player + 1
+        ),
+        None => format!("After {} seasons, you grew a real smart plant:", season.0),
+    };
+
     // header text
     commands
         .spawn_bundle(NodeBundle {
@@ -58,7 +69,7 @@ fn win_setup(
         .with_children(|parent| {
             parent.spawn_bundle(
                 TextBundle::from_section(
-                    format!("After {} seasons, you grew a real smart plant:", season.0),
+                    header_text,
                     TextStyle {
                         font: title_font.clone(),
                         font_size: 60.0,
@@ -98,6 +109,7 @@ fn win_setup(
         &mut commands,
         &plant_space_transform,
         &phenotype,
+        &display_settings,
         &image_assets,
         0,
         WinComponent,
@@ -192,6 +204,6 @@ fn win_setup(
         });
 }
 
-fn play_victory_sound(audio_assets: Res<AudioAssets>, audio: Res<AudioChannel<ForegroundChannel>>) {
-    audio.play(audio_assets.victory.clone()).with_volume(0.5);
+fn play_victory_sound(sound_effects: SoundEffects) {
+    sound_effects.play(SoundEffect::Victory);
 }